@@ -0,0 +1,301 @@
+//! Defines an adjacency array that can be navigated in both directions.
+
+use crate::{
+    adjacencyarray::{
+        count_by_node, iterators::AdjacencyArrayInEdgeIterator, next_position, AdjacencyArray,
+    },
+    graph::{BackwardNavigableGraph, EdgeRef, ForwardNavigableGraph, Graph},
+    util::vec_bytes,
+    EdgeId, IdType, NodeId,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::ops::Deref;
+
+/// An adjacency array that additionally stores the ids of the in-edges of each node,
+/// such that it can be navigated in both directions.
+///
+/// It is created from an `AdjacencyArray` with `AdjacencyArray::with_backward_index`,
+/// and dereferences to that adjacency array.
+/// The in-edges of each node are returned in edge id order.
+///
+/// Since the in-edge iterators borrow the graph, `BackwardNavigableGraph` is implemented for references to it.
+pub struct BidirectionalAdjacencyArray<N, E> {
+    graph: AdjacencyArray<N, E>,
+    first_in: Vec<EdgeId>,
+    in_edges: Vec<EdgeId>,
+}
+
+impl<N, E> BidirectionalAdjacencyArray<N, E> {
+    pub(super) fn new(graph: AdjacencyArray<N, E>) -> Self {
+        let mut first_in = Vec::new();
+        count_by_node(
+            &mut first_in,
+            graph.node_data.len(),
+            graph.edge_ends.iter().copied(),
+        );
+        let mut in_edges = vec![EdgeId::invalid(); graph.edge_ends.len()];
+        for (edge_index, &end) in graph.edge_ends.iter().enumerate() {
+            in_edges[next_position(&mut first_in, end)] = edge_index.into();
+        }
+
+        first_in.pop();
+        BidirectionalAdjacencyArray {
+            graph,
+            first_in,
+            in_edges,
+        }
+    }
+
+    /// Drops the backward index and returns the underlying adjacency array.
+    pub fn into_forward(self) -> AdjacencyArray<N, E> {
+        self.graph
+    }
+
+    /// Returns an iterator over the in-edges of the node identified by the given id.
+    pub fn in_edges(&self, id: NodeId) -> AdjacencyArrayInEdgeIterator<'_> {
+        assert!(self.is_node_id_valid(id));
+        let node_index = <NodeId as Into<usize>>::into(id);
+        let in_edge_range: core::ops::Range<usize> =
+            self.first_in[node_index].into()..self.first_in[node_index + 1].into();
+        self.in_edges[in_edge_range].iter().copied()
+    }
+
+    /// Returns an iterator over all edges incident to the node identified by the given id, regardless of their direction.
+    ///
+    /// The out-edges are returned first, followed by the in-edges.
+    /// Self-loops are returned only once.
+    pub fn incident_edges(&self, id: NodeId) -> impl Iterator<Item = EdgeId> + '_ {
+        self.out_edges(id)
+            .filter(move |&edge_id| self.edge_end(edge_id) != id)
+            .chain(self.in_edges(id))
+    }
+
+    /// Returns the amount of heap memory used by this adjacency array and its backward index in bytes.
+    ///
+    /// Heap memory owned by the node and edge data themselves is not included.
+    pub fn memory_bytes(&self) -> usize {
+        self.graph.memory_bytes() + vec_bytes(&self.first_in) + vec_bytes(&self.in_edges)
+    }
+
+    /// Checks the internal invariants of this adjacency array, including its backward index.
+    ///
+    /// Returns an error describing the first violated invariant, if any.
+    /// Adjacency arrays constructed by this crate always satisfy the invariants.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.graph.check_invariants()?;
+
+        if self.first_in.len() != self.graph.first_out.len() {
+            return Err(format!(
+                "first_in has length {}, but first_out has length {}",
+                self.first_in.len(),
+                self.graph.first_out.len()
+            ));
+        }
+        if self.first_in[0].id != 0 {
+            return Err(format!(
+                "first_in starts at {:?} instead of E0",
+                self.first_in[0]
+            ));
+        }
+        if let Some(index) = self
+            .first_in
+            .windows(2)
+            .position(|window| window[0] > window[1])
+        {
+            return Err(format!(
+                "first_in is decreasing at index {}: {:?} > {:?}",
+                index,
+                self.first_in[index],
+                self.first_in[index + 1]
+            ));
+        }
+        let last: usize = (*self.first_in.last().expect("first_in is empty")).into();
+        if last != self.in_edges.len() || last != self.graph.edge_ends.len() {
+            return Err(format!(
+                "The last entry of first_in is {}, but there are {} in-edges and {} edges",
+                last,
+                self.in_edges.len(),
+                self.graph.edge_ends.len()
+            ));
+        }
+
+        // All edge ids are in bounds and none appears twice, so the in-edges are a permutation of the edge ids.
+        let mut seen = vec![false; self.in_edges.len()];
+        for (node_index, window) in self.first_in.windows(2).enumerate() {
+            let in_edge_range: core::ops::Range<usize> = window[0].into()..window[1].into();
+            for &edge_id in &self.in_edges[in_edge_range] {
+                if !self.graph.is_edge_id_valid(edge_id) {
+                    return Err(format!(
+                        "In-edge {:?} of N{} is not a valid edge id",
+                        edge_id, node_index
+                    ));
+                }
+                let edge_index: usize = edge_id.into();
+                if seen[edge_index] {
+                    return Err(format!("Edge {:?} is an in-edge twice", edge_id));
+                }
+                seen[edge_index] = true;
+                if <usize>::from(self.graph.edge_ends[edge_index]) != node_index {
+                    return Err(format!(
+                        "In-edge {:?} of N{} does not end in N{}",
+                        edge_id, node_index, node_index
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<N, E> Deref for BidirectionalAdjacencyArray<N, E> {
+    type Target = AdjacencyArray<N, E>;
+
+    fn deref(&self) -> &AdjacencyArray<N, E> {
+        &self.graph
+    }
+}
+
+impl<N, E> core::fmt::Debug for BidirectionalAdjacencyArray<N, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("BidirectionalAdjacencyArray")
+            .field("graph", &self.graph)
+            .field("first_in_len", &self.first_in.len())
+            .finish()
+    }
+}
+
+// Implements `Graph` and `ForwardNavigableGraph` for the given type by delegating to the underlying adjacency array.
+macro_rules! impl_forward_delegation {
+    ([$($generics:tt)*] $graph_type:ty) => {
+        impl<$($generics)*> Graph<N, E> for $graph_type {
+            type NodeIdIterator = <AdjacencyArray<N, E> as Graph<N, E>>::NodeIdIterator;
+            type EdgeIdIterator = <AdjacencyArray<N, E> as Graph<N, E>>::EdgeIdIterator;
+
+            fn node_len(&self) -> IdType {
+                self.graph.node_len()
+            }
+
+            fn edge_len(&self) -> IdType {
+                self.graph.edge_len()
+            }
+
+            fn node_id_iter(&self) -> Self::NodeIdIterator {
+                self.graph.node_id_iter()
+            }
+
+            fn edge_id_iter(&self) -> Self::EdgeIdIterator {
+                self.graph.edge_id_iter()
+            }
+
+            fn node_data(&self, id: NodeId) -> &N {
+                self.graph.node_data(id)
+            }
+
+            fn edge_data(&self, id: EdgeId) -> &E {
+                self.graph.edge_data(id)
+            }
+
+            fn edge(&self, id: EdgeId) -> EdgeRef<'_, E> {
+                self.graph.edge(id)
+            }
+
+            fn edge_start(&self, id: EdgeId) -> NodeId {
+                self.graph.edge_start(id)
+            }
+
+            fn edge_end(&self, id: EdgeId) -> NodeId {
+                self.graph.edge_end(id)
+            }
+
+            fn full_edge_iter<'b>(
+                &'b self,
+            ) -> impl Iterator<Item = (EdgeId, NodeId, NodeId, &'b E)>
+            where
+                E: 'b,
+            {
+                self.graph.full_edge_iter()
+            }
+
+            fn is_node_id_valid(&self, id: NodeId) -> bool {
+                self.graph.is_node_id_valid(id)
+            }
+
+            fn is_edge_id_valid(&self, id: EdgeId) -> bool {
+                self.graph.is_edge_id_valid(id)
+            }
+        }
+
+        impl<'b, $($generics)*> ForwardNavigableGraph<'b, N, E> for $graph_type {
+            type OutEdgeIterator =
+                <AdjacencyArray<N, E> as ForwardNavigableGraph<'b, N, E>>::OutEdgeIterator;
+
+            fn out_edges(&self, id: NodeId) -> Self::OutEdgeIterator {
+                self.graph.out_edges(id)
+            }
+
+            fn out_edge_refs(&'b self, id: NodeId) -> impl Iterator<Item = EdgeRef<'b, E>> + 'b
+            where
+                E: 'b,
+            {
+                self.graph.out_edge_refs(id)
+            }
+        }
+    };
+}
+
+impl_forward_delegation!([N, E] BidirectionalAdjacencyArray<N, E>);
+impl_forward_delegation!(['a, N, E] &'a BidirectionalAdjacencyArray<N, E>);
+
+impl<'a, N, E> BackwardNavigableGraph<'a, N, E> for &'a BidirectionalAdjacencyArray<N, E> {
+    type InEdgeIterator = AdjacencyArrayInEdgeIterator<'a>;
+
+    fn in_edges(&self, id: NodeId) -> Self::InEdgeIterator {
+        BidirectionalAdjacencyArray::in_edges(*self, id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BidirectionalAdjacencyArray;
+    use crate::{adjacencyarray::AdjacencyArray, EdgeId, NodeId};
+    use alloc::vec;
+
+    fn example() -> BidirectionalAdjacencyArray<(), ()> {
+        let first_out = vec![0, 2, 3].into_iter().map(EdgeId::from).collect();
+        let edge_ends = vec![1, 1, 0].into_iter().map(NodeId::from).collect();
+        AdjacencyArray::from_raw_parts(first_out, edge_ends, vec![(), ()], vec![(), (), ()])
+            .unwrap()
+            .with_backward_index()
+    }
+
+    #[test]
+    fn test_check_invariants_corrupted() {
+        assert_eq!(example().check_invariants(), Ok(()));
+
+        let mut graph = example();
+        graph.first_in = vec![EdgeId::new(0), EdgeId::new(2), EdgeId::new(1)];
+        let error = graph.check_invariants().unwrap_err();
+        assert!(error.contains("decreasing"), "{}", error);
+
+        let mut graph = example();
+        graph.first_in = vec![EdgeId::new(0), EdgeId::new(1), EdgeId::new(2)];
+        let error = graph.check_invariants().unwrap_err();
+        assert!(error.contains("last entry"), "{}", error);
+
+        let mut graph = example();
+        graph.in_edges = vec![EdgeId::new(2), EdgeId::new(0), EdgeId::new(3)];
+        let error = graph.check_invariants().unwrap_err();
+        assert!(error.contains("not a valid edge id"), "{}", error);
+
+        let mut graph = example();
+        graph.in_edges = vec![EdgeId::new(2), EdgeId::new(0), EdgeId::new(0)];
+        let error = graph.check_invariants().unwrap_err();
+        assert!(error.contains("twice"), "{}", error);
+
+        let mut graph = example();
+        graph.in_edges = vec![EdgeId::new(0), EdgeId::new(2), EdgeId::new(1)];
+        let error = graph.check_invariants().unwrap_err();
+        assert!(error.contains("does not end in"), "{}", error);
+    }
+}
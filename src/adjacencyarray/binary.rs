@@ -0,0 +1,98 @@
+//! A compact binary format for the `AdjacencyArray`.
+//!
+//! The format starts with a header consisting of the magic bytes `GRAPHAA1` and the amount of nodes and edges,
+//! each stored as little endian `u64`.
+//! It is followed by the `first_out` and `edge_ends` arrays as little endian ids,
+//! and finally the node data and edge data, which are written and read by user-provided closures.
+
+use crate::{adjacencyarray::AdjacencyArray, EdgeId, IdType, NodeId};
+use alloc::{string::String, vec::Vec};
+use core::convert::TryInto;
+use std::io::{self, Read, Write};
+
+const MAGIC: [u8; 8] = *b"GRAPHAA1";
+
+impl<N, E> AdjacencyArray<N, E> {
+    /// Writes this adjacency array to `writer` in a compact binary format.
+    ///
+    /// The topology is written directly, while the data of each node and edge is written with `write_node` and `write_edge`.
+    /// The written graph can be loaded again with `read_binary`, using matching closures.
+    pub fn write_binary<W: Write>(
+        &self,
+        writer: &mut W,
+        mut write_node: impl FnMut(&mut W, &N) -> io::Result<()>,
+        mut write_edge: impl FnMut(&mut W, &E) -> io::Result<()>,
+    ) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&(self.node_data.len() as u64).to_le_bytes())?;
+        writer.write_all(&(self.edge_data.len() as u64).to_le_bytes())?;
+        for first_out in &self.first_out {
+            writer.write_all(&first_out.id.to_le_bytes())?;
+        }
+        for edge_end in &self.edge_ends {
+            writer.write_all(&edge_end.id.to_le_bytes())?;
+        }
+        for node_data in &self.node_data {
+            write_node(writer, node_data)?;
+        }
+        for edge_data in &self.edge_data {
+            write_edge(writer, edge_data)?;
+        }
+        Ok(())
+    }
+
+    /// Reads an adjacency array in the binary format produced by `write_binary` from `reader`.
+    ///
+    /// The data of each node and edge is read with `read_node` and `read_edge`.
+    /// Returns an error of kind `InvalidData` if the magic bytes do not match, an id is out of bounds or the topology is inconsistent.
+    pub fn read_binary<R: Read>(
+        reader: &mut R,
+        mut read_node: impl FnMut(&mut R) -> io::Result<N>,
+        mut read_edge: impl FnMut(&mut R) -> io::Result<E>,
+    ) -> io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("Unknown magic bytes".into()));
+        }
+        let node_len = read_len(reader)?;
+        let edge_len = read_len(reader)?;
+
+        let first_out = (0..=node_len)
+            .map(|_| read_id(reader).map(EdgeId::new))
+            .collect::<io::Result<Vec<_>>>()?;
+        let edge_ends = (0..edge_len)
+            .map(|_| read_id(reader).map(NodeId::new))
+            .collect::<io::Result<Vec<_>>>()?;
+        let node_data = (0..node_len)
+            .map(|_| read_node(reader))
+            .collect::<io::Result<Vec<_>>>()?;
+        let edge_data = (0..edge_len)
+            .map(|_| read_edge(reader))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Self::from_raw_parts(first_out, edge_ends, node_data, edge_data).map_err(invalid_data)
+    }
+}
+
+fn read_len(reader: &mut impl Read) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    u64::from_le_bytes(bytes)
+        .try_into()
+        .map_err(|_| invalid_data("Length incompatible with usize".into()))
+}
+
+fn read_id(reader: &mut impl Read) -> io::Result<IdType> {
+    let mut bytes = [0; core::mem::size_of::<IdType>()];
+    reader.read_exact(&mut bytes)?;
+    match IdType::from_le_bytes(bytes) {
+        // The maximum value is reserved for invalid ids and cannot be turned into an id.
+        IdType::MAX => Err(invalid_data("Id out of bounds".into())),
+        id => Ok(id),
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -4,7 +4,9 @@ use crate::{EdgeId, IdType, NodeId};
 
 /// An iterator over the nodes of an `AdjacencyArray`.
 pub type AdjacencyArrayNodeIdIterator =
-    std::iter::Map<std::ops::Range<IdType>, fn(IdType) -> NodeId>;
+    core::iter::Map<core::ops::Range<IdType>, fn(IdType) -> NodeId>;
 /// An iterator over the edges of an `AdjacencyArray`.
 pub type AdjacencyArrayEdgeIdIterator =
-    std::iter::Map<std::ops::Range<IdType>, fn(IdType) -> EdgeId>;
\ No newline at end of file
+    core::iter::Map<core::ops::Range<IdType>, fn(IdType) -> EdgeId>;
+/// An iterator over the in-edges of a node of a `BidirectionalAdjacencyArray`.
+pub type AdjacencyArrayInEdgeIterator<'a> = core::iter::Copied<core::slice::Iter<'a, EdgeId>>;
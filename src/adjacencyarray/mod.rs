@@ -4,18 +4,33 @@
 
 use crate::{
     adjacencyarray::iterators::{AdjacencyArrayEdgeIdIterator, AdjacencyArrayNodeIdIterator},
-    graph::{EdgeRef, Graph},
+    graph::{Edge, EdgeRef, Graph, GraphModificationError},
     simplegraph::SimpleGraph,
-    util::PrefixSum,
+    util::{vec_bytes, PrefixSum},
     EdgeId, IdType, NodeId,
 };
-use std::convert::TryInto;
-use superslice::Ext;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryInto;
 use crate::graph::ForwardNavigableGraph;
 
+pub use bidirectional::BidirectionalAdjacencyArray;
+
+mod bidirectional;
+#[cfg(feature = "std")]
+mod binary;
 pub mod iterators;
+#[cfg(feature = "rayon")]
+mod parallel;
 
 /// A graph represented as adjacency array.
+///
+/// The adjacency array can only be navigated forward.
+/// Use `with_backward_index` to additionally navigate it backward.
 pub struct AdjacencyArray<N, E> {
     first_out: Vec<EdgeId>,
     edge_ends: Vec<NodeId>,
@@ -23,12 +38,387 @@ pub struct AdjacencyArray<N, E> {
     edge_data: Vec<E>,
 }
 
+/// The lengths and capacities of the internal vectors of an `AdjacencyArray`, as returned by `AdjacencyArray::stats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdjacencyArrayStats {
+    /// The amount of nodes.
+    pub node_len: usize,
+    /// The amount of edges.
+    pub edge_len: usize,
+    /// The length of the `first_out` array, which is one more than the amount of nodes.
+    pub first_out_len: usize,
+    /// The capacity of the `first_out` array.
+    pub first_out_capacity: usize,
+    /// The capacity of the array of edge end nodes.
+    pub edge_ends_capacity: usize,
+    /// The capacity of the array of node data.
+    pub node_data_capacity: usize,
+    /// The capacity of the array of edge data.
+    pub edge_data_capacity: usize,
+}
+
+impl<N, E> AdjacencyArray<N, E> {
+    /// Creates an adjacency array directly from its internal arrays.
+    ///
+    /// `first_out` contains one entry per node plus a trailing entry equal to the amount of edges.
+    /// The out-edges of node `i` are the edges from `first_out[i]` up to, but excluding, `first_out[i + 1]`.
+    /// `edge_ends` and `edge_data` contain the end node and data of each edge,
+    /// and `node_data` contains the data of each node.
+    ///
+    /// The arrays are validated with `check_invariants`, and an error is returned if they are inconsistent.
+    pub fn from_raw_parts(
+        first_out: Vec<EdgeId>,
+        edge_ends: Vec<NodeId>,
+        node_data: Vec<N>,
+        edge_data: Vec<E>,
+    ) -> Result<Self, String> {
+        let result = AdjacencyArray {
+            first_out,
+            edge_ends,
+            node_data,
+            edge_data,
+        };
+        result.check_invariants()?;
+        Ok(result)
+    }
+
+    /// Creates an adjacency array directly from an iterator over edges, without building an intermediate `SimpleGraph`.
+    ///
+    /// `node_data` contains the data of each node.
+    /// The out-edges of each node keep the order in which they are returned by the iterator.
+    /// Returns an error if `node_data` does not have `node_count` entries,
+    /// or if an edge refers to a node id that is not smaller than `node_count`.
+    pub fn from_edges(
+        node_count: usize,
+        edges: impl Iterator<Item = Edge<E>>,
+        node_data: Vec<N>,
+    ) -> Result<Self, GraphModificationError>
+    where
+        E: Default + Clone,
+    {
+        if node_data.len() != node_count {
+            return Err(GraphModificationError::NodeDataLenMismatch);
+        }
+        let is_valid = |id: NodeId| id.is_valid() && <usize>::from(id) < node_count;
+
+        let mut buffered_edges = Vec::with_capacity(edges.size_hint().0);
+        for edge in edges {
+            if !is_valid(edge.start()) {
+                return Err(GraphModificationError::StartNodeDoesNotExist);
+            } else if !is_valid(edge.end()) {
+                return Err(GraphModificationError::EndNodeDoesNotExist);
+            }
+            buffered_edges.push(edge);
+        }
+
+        let mut offsets = Vec::new();
+        count_by_node(
+            &mut offsets,
+            node_count,
+            buffered_edges.iter().map(Edge::start),
+        );
+        let (first_out, edge_ends, edge_data) = group_by_start(
+            offsets,
+            buffered_edges
+                .into_iter()
+                .map(|edge| (edge.start(), edge.end(), edge.into_data())),
+        );
+
+        Ok(AdjacencyArray {
+            first_out,
+            edge_ends,
+            node_data,
+            edge_data,
+        })
+    }
+
+    /// Creates an adjacency array with the given amount of nodes with default node data, and no edges.
+    pub fn with_node_count(node_len: IdType) -> Self
+    where
+        N: Default,
+        E: Default,
+    {
+        let node_len: usize = node_len
+            .try_into()
+            .expect("Node len incompatible with usize");
+        AdjacencyArray {
+            first_out: vec![EdgeId::new(0); node_len + 1],
+            edge_ends: Vec::new(),
+            node_data: (0..node_len).map(|_| N::default()).collect(),
+            edge_data: Vec::new(),
+        }
+    }
+
+    /// Drops the edge data of this graph, keeping its topology and all node and edge ids.
+    ///
+    /// The topology is moved into the result without copying it.
+    pub fn forget_edge_data(self) -> AdjacencyArray<N, ()> {
+        AdjacencyArray {
+            first_out: self.first_out,
+            edge_ends: self.edge_ends,
+            node_data: self.node_data,
+            edge_data: vec![(); self.edge_data.len()],
+        }
+    }
+
+    /// Returns a copy of this graph with its nodes relabeled according to the given permutation.
+    ///
+    /// `permutation[old_index]` is the new id of the node that currently has id `old_index`.
+    /// Edges are moved together with their start nodes and their end nodes are relabeled accordingly.
+    /// The out-edges of each node keep their order.
+    ///
+    /// Panics if `permutation` is not a permutation of the node ids of this graph.
+    pub fn relabel_nodes(&self, permutation: &[NodeId]) -> AdjacencyArray<N, E>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let node_len = self.node_data.len();
+        assert_eq!(
+            permutation.len(),
+            node_len,
+            "Permutation length does not match node len"
+        );
+        let mut inverse = vec![NodeId::invalid(); node_len];
+        for (old_index, &new_id) in permutation.iter().enumerate() {
+            assert!(
+                self.is_node_id_valid(new_id),
+                "Permutation contains invalid node id {:?}",
+                new_id
+            );
+            let new_index: usize = new_id.into();
+            assert!(
+                !inverse[new_index].is_valid(),
+                "Permutation contains node id {:?} twice",
+                new_id
+            );
+            inverse[new_index] = old_index.into();
+        }
+
+        let mut first_out = Vec::with_capacity(node_len + 1);
+        let mut edge_ends = Vec::with_capacity(self.edge_ends.len());
+        let mut node_data = Vec::with_capacity(node_len);
+        let mut edge_data = Vec::with_capacity(self.edge_data.len());
+        first_out.push(EdgeId::new(0));
+        for &old_id in &inverse {
+            let old_index: usize = old_id.into();
+            node_data.push(self.node_data[old_index].clone());
+            let edge_range: core::ops::Range<usize> =
+                self.first_out[old_index].into()..self.first_out[old_index + 1].into();
+            for edge_index in edge_range {
+                let old_end: usize = self.edge_ends[edge_index].into();
+                edge_ends.push(permutation[old_end]);
+                edge_data.push(self.edge_data[edge_index].clone());
+            }
+            first_out.push(edge_ends.len().into());
+        }
+
+        AdjacencyArray {
+            first_out,
+            edge_ends,
+            node_data,
+            edge_data,
+        }
+    }
+
+    /// Builds the index of the in-edges of each node, such that the result can be navigated backward as well.
+    ///
+    /// The index takes one id per node and one id per edge, and building it takes O(V + E) time.
+    pub fn with_backward_index(self) -> BidirectionalAdjacencyArray<N, E> {
+        BidirectionalAdjacencyArray::new(self)
+    }
+
+    /// Returns the end nodes of the out-edges of the node identified by the given id, as a slice.
+    /// The order is the same as in `out_edges`.
+    pub fn out_edge_ends(&self, id: NodeId) -> &[NodeId] {
+        &self.edge_ends[self.out_edge_range(id)]
+    }
+
+    /// Returns the data of the out-edges of the node identified by the given id, as a slice.
+    /// The order is the same as in `out_edges`.
+    pub fn out_edge_data(&self, id: NodeId) -> &[E] {
+        &self.edge_data[self.out_edge_range(id)]
+    }
+
+    /// Returns an iterator over all nodes in node id order, together with the end nodes and data of their out-edges as slices.
+    /// The order of the out-edges is the same as in `out_edges`.
+    pub fn edges_by_source(&self) -> impl Iterator<Item = (NodeId, &[NodeId], &[E])> {
+        self.first_out
+            .windows(2)
+            .enumerate()
+            .map(move |(node_index, window)| {
+                let range: core::ops::Range<usize> = window[0].into()..window[1].into();
+                (
+                    NodeId::from(node_index),
+                    &self.edge_ends[range.clone()],
+                    &self.edge_data[range],
+                )
+            })
+    }
+
+    /// Returns the end nodes of all edges, indexed by edge id.
+    pub fn edge_ends_slice(&self) -> &[NodeId] {
+        &self.edge_ends
+    }
+
+    /// Returns the data of all edges, indexed by edge id.
+    pub fn edge_data_slice(&self) -> &[E] {
+        &self.edge_data
+    }
+
+    /// Exports this adjacency array in compressed sparse row format, as the triple of `indptr`, `indices` and edge data.
+    ///
+    /// `indptr` contains the index of the first out-edge of each node plus a trailing entry equal to the amount of edges,
+    /// `indices` contains the end node of each edge, and the edge data is referenced in edge id order.
+    pub fn to_csr(&self) -> (Vec<usize>, Vec<usize>, Vec<&E>) {
+        (
+            self.first_out.iter().map(|&id| id.into()).collect(),
+            self.edge_ends.iter().map(|&id| id.into()).collect(),
+            self.edge_data.iter().collect(),
+        )
+    }
+
+    fn out_edge_range(&self, id: NodeId) -> core::ops::Range<usize> {
+        assert!(self.is_node_id_valid(id));
+        let node_index = <NodeId as Into<usize>>::into(id);
+        self.first_out[node_index].into()..self.first_out[node_index + 1].into()
+    }
+
+    /// Returns the amount of heap memory used by this adjacency array in bytes.
+    ///
+    /// This is computed from the capacities of the internal vectors.
+    /// Heap memory owned by the node and edge data themselves is not included.
+    pub fn memory_bytes(&self) -> usize {
+        vec_bytes(&self.first_out)
+            + vec_bytes(&self.edge_ends)
+            + vec_bytes(&self.node_data)
+            + vec_bytes(&self.edge_data)
+    }
+
+    /// Returns the lengths and capacities of the internal vectors of this adjacency array.
+    pub fn stats(&self) -> AdjacencyArrayStats {
+        AdjacencyArrayStats {
+            node_len: self.node_data.len(),
+            edge_len: self.edge_data.len(),
+            first_out_len: self.first_out.len(),
+            first_out_capacity: self.first_out.capacity(),
+            edge_ends_capacity: self.edge_ends.capacity(),
+            node_data_capacity: self.node_data.capacity(),
+            edge_data_capacity: self.edge_data.capacity(),
+        }
+    }
+
+    /// Checks the internal invariants of this adjacency array.
+    ///
+    /// Returns an error describing the first violated invariant, if any.
+    /// Adjacency arrays constructed by this crate always satisfy the invariants.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let first = match self.first_out.first() {
+            Some(first) => first,
+            None => return Err("first_out is empty".to_string()),
+        };
+        if first.id != 0 {
+            return Err(format!("first_out starts at {:?} instead of E0", first));
+        }
+        if let Some(index) = self
+            .first_out
+            .windows(2)
+            .position(|window| window[0] > window[1])
+        {
+            return Err(format!(
+                "first_out is decreasing at index {}: {:?} > {:?}",
+                index,
+                self.first_out[index],
+                self.first_out[index + 1]
+            ));
+        }
+
+        let last: usize = (*self.first_out.last().expect("first_out is empty")).into();
+        if last != self.edge_ends.len() {
+            return Err(format!(
+                "The last entry of first_out is {}, but there are {} edge ends",
+                last,
+                self.edge_ends.len()
+            ));
+        }
+
+        let node_len = self.first_out.len() - 1;
+        if let Some(end) = self
+            .edge_ends
+            .iter()
+            .find(|end| !end.is_valid() || <usize>::from(**end) >= node_len)
+        {
+            return Err(format!(
+                "Edge end {:?} is not a valid node id in a graph with {} nodes",
+                end, node_len
+            ));
+        }
+
+        if self.node_data.len() != node_len {
+            return Err(format!(
+                "There are {} node data entries, but {} nodes",
+                self.node_data.len(),
+                node_len
+            ));
+        }
+        if self.edge_data.len() != self.edge_ends.len() {
+            return Err(format!(
+                "There are {} edge data entries, but {} edges",
+                self.edge_data.len(),
+                self.edge_ends.len()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<N, E: Copy> AdjacencyArray<N, E> {
+    /// Returns a copy of the data of the edge identified by the given id.
+    ///
+    /// This is a shorthand for `*graph.edge_data(id)` for small edge data like numeric weights.
+    pub fn edge_weight(&self, id: EdgeId) -> E {
+        *self.edge_data(id)
+    }
+}
+
+impl<N: PartialEq> AdjacencyArray<N, f64> {
+    /// Returns true if this graph and the given graph are equal up to floating point errors in the edge data.
+    ///
+    /// The topology, including node and edge ids, and the node data must be exactly equal,
+    /// while the data of edges with the same id may differ by at most `epsilon`.
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.first_out == other.first_out
+            && self.edge_ends == other.edge_ends
+            && self.node_data == other.node_data
+            && self
+                .edge_data
+                .iter()
+                .zip(&other.edge_data)
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl<N, E> core::fmt::Debug for AdjacencyArray<N, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("AdjacencyArray")
+            .field("node_len", &self.node_data.len())
+            .field("edge_len", &self.edge_ends.len())
+            .field("first_out_len", &self.first_out.len())
+            .finish()
+    }
+}
+
 impl<N, E> Graph<N, E> for AdjacencyArray<N, E> {
     type NodeIdIterator = AdjacencyArrayNodeIdIterator;
     type EdgeIdIterator = AdjacencyArrayEdgeIdIterator;
 
     fn node_len(&self) -> IdType {
-        (self.first_out.len() - 1)
+        // first_out always contains a trailing entry, which is checked when constructing from raw parts.
+        self.first_out
+            .len()
+            .checked_sub(1)
+            .expect("first_out is empty, the adjacency array is corrupted")
             .try_into()
             .unwrap_or_else(|_| panic!("Node len not compatible with usize"))
     }
@@ -40,11 +430,11 @@ impl<N, E> Graph<N, E> for AdjacencyArray<N, E> {
     }
 
     fn node_id_iter(&self) -> Self::NodeIdIterator {
-        (0..self.node_len()).map(|id| NodeId::new(id))
+        (0..self.node_len()).map(NodeId::new)
     }
 
     fn edge_id_iter(&self) -> Self::EdgeIdIterator {
-        (0..self.edge_len()).map(|id| EdgeId::new(id))
+        (0..self.edge_len()).map(EdgeId::new)
     }
 
     fn node_data(&self, id: NodeId) -> &N {
@@ -57,17 +447,16 @@ impl<N, E> Graph<N, E> for AdjacencyArray<N, E> {
         &self.edge_data[<EdgeId as Into<usize>>::into(id)]
     }
 
-    fn edge(&self, id: EdgeId) -> EdgeRef<E> {
+    fn edge(&self, id: EdgeId) -> EdgeRef<'_, E> {
         assert!(self.is_edge_id_valid(id));
-        let start = self.edge_start(id);
-        let end = self.edge_end(id);
+        let (start, end) = self.edge_endpoints(id);
         let data = self.edge_data(id);
         EdgeRef::new(start, end, data)
     }
 
     fn edge_start(&self, id: EdgeId) -> NodeId {
         assert!(self.is_edge_id_valid(id));
-        (self.first_out.upper_bound(&id.into()) - 1).into()
+        (self.first_out.partition_point(|&first_out| first_out <= id) - 1).into()
     }
 
     fn edge_end(&self, id: EdgeId) -> NodeId {
@@ -75,6 +464,28 @@ impl<N, E> Graph<N, E> for AdjacencyArray<N, E> {
         self.edge_ends[<EdgeId as Into<usize>>::into(id)]
     }
 
+    fn edge_endpoints(&self, id: EdgeId) -> (NodeId, NodeId) {
+        // Finding the start node requires a binary search, so it is done only once.
+        let start = self.edge_start(id);
+        (start, self.edge_ends[<EdgeId as Into<usize>>::into(id)])
+    }
+
+    fn full_edge_iter<'b>(&'b self) -> impl Iterator<Item = (EdgeId, NodeId, NodeId, &'b E)>
+    where
+        E: 'b,
+    {
+        // Edges are grouped by start node, so the start nodes are read from first_out instead of searching it for each edge.
+        self.first_out
+            .windows(2)
+            .enumerate()
+            .flat_map(|(node_index, window)| {
+                (window[0].id..window[1].id)
+                    .map(move |id| (EdgeId::new(id), NodeId::from(node_index)))
+            })
+            .zip(self.edge_ends.iter().zip(&self.edge_data))
+            .map(|((id, start), (&end, data))| (id, start, end, data))
+    }
+
     fn is_node_id_valid(&self, id: NodeId) -> bool {
         id.is_valid() && id.id < self.node_len()
     }
@@ -85,7 +496,7 @@ impl<N, E> Graph<N, E> for AdjacencyArray<N, E> {
 }
 
 impl<'a, N, E> ForwardNavigableGraph<'a, N, E> for AdjacencyArray<N, E> {
-    type OutEdgeIterator = std::iter::Map<std::ops::Range<IdType>, fn(IdType) -> EdgeId>;
+    type OutEdgeIterator = core::iter::Map<core::ops::Range<IdType>, fn(IdType) -> EdgeId>;
 
     fn out_edges(&self, id: NodeId) -> Self::OutEdgeIterator {
         assert!(self.is_node_id_valid(id));
@@ -93,11 +504,73 @@ impl<'a, N, E> ForwardNavigableGraph<'a, N, E> for AdjacencyArray<N, E> {
         let edge_id_offset = self.first_out[node_index].id;
         let edge_id_limit = self.first_out[node_index + 1].id;
         // TODO replace with Range<EdgeId> once Step API is stable (https://github.com/rust-lang/rust/issues/42168)
-        (edge_id_offset..edge_id_limit).map(|id| EdgeId::new(id))
+        (edge_id_offset..edge_id_limit).map(EdgeId::new)
+    }
+
+    fn out_edge_refs(&'a self, id: NodeId) -> impl Iterator<Item = EdgeRef<'a, E>> + 'a
+    where
+        E: 'a,
+    {
+        let range = self.out_edge_range(id);
+        self.edge_ends[range.clone()]
+            .iter()
+            .zip(&self.edge_data[range])
+            .map(move |(&end, data)| EdgeRef::new(id, end, data))
     }
 }
 
-fn convert_from<N: Clone, E: Default + Clone, G: Graph<N, E>>(source: &G) -> AdjacencyArray<N, E> {
+/// Counts the occurrences of each node in `nodes` into `offsets`, resized to `node_len + 2` entries, and computes its prefix sum.
+///
+/// The count of node i is stored at index i + 2, such that after the prefix sum, index i + 1 holds the amount of occurrences of nodes smaller than i.
+/// This is the position of the first occurrence of node i when grouping the occurrences by node.
+fn count_by_node(offsets: &mut Vec<EdgeId>, node_len: usize, nodes: impl Iterator<Item = NodeId>) {
+    offsets.clear();
+    offsets.resize(node_len + 2, EdgeId::new(0));
+    for node in nodes {
+        offsets[<usize>::from(node) + 2].id += 1;
+    }
+    offsets.prefix_sum();
+}
+
+/// Returns the position of the next occurrence of `node` when grouping the occurrences by node.
+///
+/// `offsets` must be computed by `count_by_node`, and this must be called once for each occurrence.
+/// The entry at index i + 1 is advanced to the end of the occurrences of node i, such that afterwards,
+/// `offsets[..=node_len]` holds the position of the first occurrence of each node.
+fn next_position(offsets: &mut [EdgeId], node: NodeId) -> usize {
+    let raw_position = &mut offsets[<usize>::from(node) + 1].id;
+    let position = (*raw_position).try_into().expect("Edge id out of bounds");
+    *raw_position += 1;
+    position
+}
+
+/// Groups edges by their start node, and returns the `first_out`, `edge_ends` and `edge_data` arrays of the result.
+///
+/// `offsets` must be computed by `count_by_node` from the start nodes of the edges returned by `edges`.
+/// Edges are placed in iteration order, such that the out-edges of each node keep their relative order.
+fn group_by_start<E: Default + Clone>(
+    offsets: Vec<EdgeId>,
+    edges: impl Iterator<Item = (NodeId, NodeId, E)>,
+) -> (Vec<EdgeId>, Vec<NodeId>, Vec<E>) {
+    let mut first_out = offsets;
+    let edge_len: usize = (*first_out.last().expect("first_out is empty")).into();
+    let mut edge_ends = vec![NodeId::invalid(); edge_len];
+    let mut edge_data = vec![E::default(); edge_len];
+
+    for (start, end, data) in edges {
+        let edge_index = next_position(&mut first_out, start);
+        edge_ends[edge_index] = end;
+        edge_data[edge_index] = data;
+    }
+
+    first_out.pop();
+    (first_out, edge_ends, edge_data)
+}
+
+fn convert_from<N: Clone, E: Clone, G: Graph<N, E>>(
+    source: &G,
+    scratch: &mut ConversionScratch,
+) -> AdjacencyArray<N, E> {
     let node_len: usize = source
         .node_len()
         .try_into()
@@ -106,51 +579,165 @@ fn convert_from<N: Clone, E: Default + Clone, G: Graph<N, E>>(source: &G) -> Adj
         .edge_len()
         .try_into()
         .expect("Edge len incompatible with usize");
-    let mut first_out = vec![EdgeId::new(0); node_len + 2];
-    let mut edge_ends = vec![NodeId::invalid(); edge_len];
-    let node_data: Vec<_> = source
-        .node_id_iter()
-        .map(|id| source.node_data(id).clone())
-        .collect();
-    let mut edge_data = vec![E::default(); edge_len];
+    let offsets = &mut scratch.offsets;
+    count_by_node(
+        offsets,
+        node_len,
+        source.edge_id_iter().map(|id| source.edge_start(id)),
+    );
 
-    for edge in source.edge_id_iter().map(|id| source.edge(id)) {
-        let count_index: usize = (edge.start().id + 2)
-            .try_into()
-            .expect("Node id out of bounds");
-        assert!(count_index < first_out.len(), "Count index out of bounds");
-        first_out[count_index].id += 1;
+    // The source edges are staged in their order in the result, such that the result arrays can be collected with their exact size.
+    let order = &mut scratch.order;
+    order.clear();
+    order.resize(edge_len, EdgeId::invalid());
+    for id in source.edge_id_iter() {
+        order[next_position(offsets, source.edge_start(id))] = id;
     }
 
-    first_out.prefix_sum();
-
-    for edge in source.edge_id_iter().map(|id| source.edge(id)) {
-        let node_index: usize = (edge.start().id + 1)
-            .try_into()
-            .expect("Node id out of bounds");
-        assert!(
-            node_index < first_out.len() - 1,
-            "Lookup index out of bounds"
-        );
-        let raw_edge_index = &mut first_out[node_index].id;
-        let edge_index: usize = (*raw_edge_index).try_into().expect("Edge id out of bounds");
-        edge_ends[edge_index] = edge.end();
-        edge_data[edge_index] = edge.data().clone();
-        *raw_edge_index += 1;
+    AdjacencyArray {
+        first_out: offsets[..=node_len].to_vec(),
+        edge_ends: order.iter().map(|&id| source.edge_end(id)).collect(),
+        node_data: source
+            .node_id_iter()
+            .map(|id| source.node_data(id).clone())
+            .collect(),
+        edge_data: order
+            .iter()
+            .map(|&id| source.edge_data(id).clone())
+            .collect(),
     }
+}
 
-    first_out.pop();
+/// Reusable buffers for `AdjacencyArray::convert_into`.
+///
+/// The buffers grow to the size of the largest converted graph and are reused by later conversions.
+#[derive(Debug, Default, Clone)]
+pub struct ConversionScratch {
+    // The per-node edge counts, which become the first out-edge of each node while placing the edges.
+    offsets: Vec<EdgeId>,
+    // The ids of the source edges in their order in the result.
+    order: Vec<EdgeId>,
+}
 
-    AdjacencyArray {
-        first_out,
-        edge_ends,
-        node_data,
-        edge_data,
+impl ConversionScratch {
+    /// Creates a new scratch without any allocated buffers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the amount of heap memory used by the buffers of this scratch in bytes.
+    pub fn memory_bytes(&self) -> usize {
+        vec_bytes(&self.offsets) + vec_bytes(&self.order)
     }
 }
 
+/// Converts a `SimpleGraph` into an adjacency array.
+///
+/// Node ids are always preserved.
+/// Edges are grouped by their start node, keeping the relative order of edges with the same start node.
+/// Edge ids are therefore only preserved if the edges of the source are sorted by their start node,
+/// which can be achieved with `SimpleGraph::sort_edges_by_start`.
 impl<N: Clone, E: Default + Clone> From<&SimpleGraph<N, E>> for AdjacencyArray<N, E> {
     fn from(source: &SimpleGraph<N, E>) -> Self {
-        convert_from(source)
+        convert_from(source, &mut ConversionScratch::new())
+    }
+}
+
+impl<N: Clone, E: Clone> AdjacencyArray<N, E> {
+    /// Converts the given `SimpleGraph` into an adjacency array with exactly the same node and edge ids,
+    /// or returns `None` if that is not possible.
+    ///
+    /// Unlike `AdjacencyArray::from`, which reorders edges that are not sorted by their start node,
+    /// this guarantees that `result.edge(id) == source.edge(id)` for every edge id.
+    /// Since an adjacency array stores the out-edges of each node consecutively, this is only possible
+    /// if the edges of the source are sorted by their start node, and `None` is returned otherwise.
+    /// `SimpleGraph::sort_edges_by_start` establishes that order, changing the edge ids of the source.
+    ///
+    /// As the edges are already grouped, this needs only a single pass over them instead of a counting sort.
+    pub fn from_simple_stable(source: &SimpleGraph<N, E>) -> Option<Self> {
+        let node_len: usize = source
+            .node_len()
+            .try_into()
+            .expect("Node len incompatible with usize");
+        let edge_len: usize = source
+            .edge_len()
+            .try_into()
+            .expect("Edge len incompatible with usize");
+        let mut first_out = Vec::with_capacity(node_len + 1);
+        let mut edge_ends = Vec::with_capacity(edge_len);
+        let mut edge_data = Vec::with_capacity(edge_len);
+
+        for edge in source.edge_id_iter().map(|id| source.edge(id)) {
+            let start_index: usize = edge.start().into();
+            // first_out already contains the first out-edge of every node up to the start node of the previous edge.
+            if start_index + 1 < first_out.len() {
+                return None;
+            }
+            while first_out.len() <= start_index {
+                first_out.push(EdgeId::from(edge_ends.len()));
+            }
+            edge_ends.push(edge.end());
+            edge_data.push(edge.data().clone());
+        }
+        while first_out.len() <= node_len {
+            first_out.push(EdgeId::from(edge_ends.len()));
+        }
+
+        let node_data = source
+            .node_id_iter()
+            .map(|id| source.node_data(id).clone())
+            .collect();
+        Some(AdjacencyArray {
+            first_out,
+            edge_ends,
+            node_data,
+            edge_data,
+        })
+    }
+
+    /// Converts the given `SimpleGraph` into an adjacency array, using the buffers of `scratch` for temporary data.
+    ///
+    /// The result is identical to the result of `AdjacencyArray::from`.
+    /// Reusing the same scratch for many conversions avoids allocating the temporary buffers for each conversion,
+    /// and the arrays of the result are allocated with their exact size.
+    pub fn convert_into(source: &SimpleGraph<N, E>, scratch: &mut ConversionScratch) -> Self {
+        convert_from(source, scratch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdjacencyArray;
+    use crate::{EdgeId, NodeId};
+    use alloc::vec;
+
+    #[test]
+    fn test_check_invariants_corrupted() {
+        let adjacency_array = AdjacencyArray {
+            first_out: vec![EdgeId::new(0), EdgeId::new(2), EdgeId::new(1)],
+            edge_ends: vec![NodeId::new(1)],
+            node_data: vec![(), ()],
+            edge_data: vec![()],
+        };
+        let error = adjacency_array.check_invariants().unwrap_err();
+        assert!(error.contains("decreasing"), "{}", error);
+
+        let adjacency_array = AdjacencyArray {
+            first_out: vec![EdgeId::new(0), EdgeId::new(1), EdgeId::new(1)],
+            edge_ends: vec![NodeId::new(2)],
+            node_data: vec![(), ()],
+            edge_data: vec![()],
+        };
+        let error = adjacency_array.check_invariants().unwrap_err();
+        assert!(error.contains("N2"), "{}", error);
+
+        let adjacency_array = AdjacencyArray {
+            first_out: vec![EdgeId::new(0), EdgeId::new(1), EdgeId::new(1)],
+            edge_ends: vec![NodeId::new(1)],
+            node_data: vec![()],
+            edge_data: vec![()],
+        };
+        let error = adjacency_array.check_invariants().unwrap_err();
+        assert!(error.contains("node data"), "{}", error);
     }
 }
@@ -0,0 +1,107 @@
+//! Parallel construction of the `AdjacencyArray` with rayon.
+
+use crate::{
+    adjacencyarray::AdjacencyArray, graph::Graph, simplegraph::SimpleGraph, util::PrefixSum,
+    EdgeId, IdType,
+};
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+use rayon::prelude::*;
+
+impl<N: Clone + Send + Sync, E: Clone + Send + Sync> AdjacencyArray<N, E> {
+    /// Converts the given `SimpleGraph` into an adjacency array using multiple threads.
+    ///
+    /// The result is identical to the result of `AdjacencyArray::from`, including the order of the edges.
+    pub fn par_from(source: &SimpleGraph<N, E>) -> Self {
+        par_convert_from(source)
+    }
+}
+
+fn par_convert_from<N, E, G>(source: &G) -> AdjacencyArray<N, E>
+where
+    N: Clone + Send + Sync,
+    E: Clone + Send + Sync,
+    G: Graph<N, E> + Sync,
+{
+    let node_len: usize = source
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let edge_len: usize = source
+        .edge_len()
+        .try_into()
+        .expect("Edge len incompatible with usize");
+    let chunk_len = edge_len.div_ceil(rayon::current_num_threads()).max(1);
+    let chunk_ranges: Vec<_> = (0..edge_len)
+        .step_by(chunk_len)
+        .map(|chunk_start| chunk_start..(chunk_start + chunk_len).min(edge_len))
+        .collect();
+
+    // Each chunk of edges counts the out-degrees of its start nodes separately.
+    let mut chunk_counts: Vec<Vec<IdType>> = chunk_ranges
+        .par_iter()
+        .map(|range| {
+            let mut counts = vec![0; node_len];
+            for edge_index in range.clone() {
+                counts[<usize>::from(source.edge_start(EdgeId::from(edge_index)))] += 1;
+            }
+            counts
+        })
+        .collect();
+
+    let out_degrees: Vec<EdgeId> = (0..node_len)
+        .into_par_iter()
+        .map(|node_index| EdgeId::new(chunk_counts.iter().map(|counts| counts[node_index]).sum()))
+        .collect();
+    let (mut first_out, edge_len_id) = out_degrees.prefix_sum_cloned();
+    first_out.push(edge_len_id);
+
+    // Turn the counts into the position of the first out-edge of each node within each chunk.
+    let mut offsets: Vec<IdType> = first_out[..node_len].iter().map(|id| id.id).collect();
+    for counts in &mut chunk_counts {
+        for (count, offset) in counts.iter_mut().zip(&mut offsets) {
+            let chunk_out_degree = *count;
+            *count = *offset;
+            *offset += chunk_out_degree;
+        }
+    }
+
+    // Edges are placed in source iteration order, such that the out-edges of each node keep their insertion order.
+    let positions: Vec<usize> = chunk_ranges
+        .into_par_iter()
+        .zip(chunk_counts)
+        .flat_map_iter(|(range, mut offsets)| {
+            range.map(move |edge_index| {
+                let offset =
+                    &mut offsets[<usize>::from(source.edge_start(EdgeId::from(edge_index)))];
+                let position = (*offset).try_into().expect("Edge id out of bounds");
+                *offset += 1;
+                position
+            })
+        })
+        .collect();
+    let mut source_edges = vec![EdgeId::invalid(); edge_len];
+    for (edge_index, position) in positions.into_iter().enumerate() {
+        source_edges[position] = EdgeId::from(edge_index);
+    }
+
+    let edge_ends = source_edges
+        .par_iter()
+        .map(|&id| source.edge_end(id))
+        .collect();
+    let edge_data = source_edges
+        .par_iter()
+        .map(|&id| source.edge_data(id).clone())
+        .collect();
+    let node_data = (0..node_len)
+        .into_par_iter()
+        .map(|node_index| source.node_data(node_index.into()).clone())
+        .collect();
+
+    AdjacencyArray {
+        first_out,
+        edge_ends,
+        node_data,
+        edge_data,
+    }
+}
@@ -0,0 +1,1709 @@
+//! Algorithms operating on the different graph traits.
+//!
+//! The algorithms in this module are generic over the graph representation.
+//! They only require the graph traits they actually need, e.g. `ForwardNavigableGraph` for traversals.
+
+use crate::{
+    graph::{Edge, EdgeWeight, ForwardNavigableGraph, Graph, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+    util::{NodeBitset, XorShift64Star},
+    EdgeId, IdType, NodeId,
+};
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::{Ordering, Reverse},
+    convert::TryInto,
+    ops::Add,
+};
+
+/// Returns the forward adjacency of the given graph as one vector of out-neighbors per node.
+///
+/// The outer vector is indexed by node id, the inner vectors contain the end nodes of the out-edges of the respective node.
+/// Isolated nodes are represented by empty inner vectors.
+pub fn adjacency_lists<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<Vec<NodeId>> {
+    graph
+        .node_id_iter()
+        .map(|id| {
+            graph
+                .out_edges(id)
+                .map(|edge_id| graph.edge_end(edge_id))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns true if the given graph does not contain any directed cycle.
+///
+/// Implemented as an iterative depth-first search with a three-color marking.
+/// A node is white if it has not been discovered yet, gray while it is on the search stack and black once it is finished.
+/// An edge to a gray node is a back edge and closes a cycle.
+/// Self-loops count as cycles.
+pub fn is_acyclic<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> bool {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Color {
+        White,
+        Gray,
+        Black,
+    }
+
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut colors = vec![Color::White; node_len];
+    let mut stack = Vec::new();
+
+    for root in graph.node_id_iter() {
+        if colors[<usize>::from(root)] != Color::White {
+            continue;
+        }
+
+        colors[<usize>::from(root)] = Color::Gray;
+        stack.push((root, graph.out_edges(root)));
+
+        while let Some((node, out_edges)) = stack.last_mut() {
+            let node = *node;
+            if let Some(edge_id) = out_edges.next() {
+                let end = graph.edge_end(edge_id);
+                match colors[<usize>::from(end)] {
+                    Color::White => {
+                        colors[<usize>::from(end)] = Color::Gray;
+                        stack.push((end, graph.out_edges(end)));
+                    },
+                    Color::Gray => return false,
+                    Color::Black => {},
+                }
+            } else {
+                colors[<usize>::from(node)] = Color::Black;
+                stack.pop();
+            }
+        }
+    }
+
+    true
+}
+
+/// An error returned by algorithms that require an acyclic graph, if the given graph contains a directed cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleError;
+
+impl core::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "The graph contains a cycle")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CycleError {}
+
+/// Returns an iterator over the ids of all nodes of the given graph in topological order,
+/// or an error if the graph contains a directed cycle.
+///
+/// The order is computed lazily with Kahn's algorithm: a node is returned once all of its predecessors have been returned.
+/// Nodes that become available at the same time are returned in the order in which they become available,
+/// starting with the nodes without in-edges in node id order.
+/// Checking for cycles and computing the in-degrees takes O(V + E) time up front,
+/// and iterating the whole order takes another O(V + E) time.
+pub fn topological_iter<'a, N: 'a, E: 'a, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Result<impl Iterator<Item = NodeId> + 'a, CycleError> {
+    if !is_acyclic(graph) {
+        return Err(CycleError);
+    }
+
+    let mut in_degrees = compute_in_degrees(graph);
+    let mut queue: VecDeque<_> = graph
+        .node_id_iter()
+        .filter(|&id| in_degrees[<usize>::from(id)] == 0)
+        .collect();
+    Ok(core::iter::from_fn(move || {
+        let node = queue.pop_front()?;
+        for edge_id in graph.out_edges(node) {
+            let end = graph.edge_end(edge_id);
+            let in_degree = &mut in_degrees[<usize>::from(end)];
+            *in_degree -= 1;
+            if *in_degree == 0 {
+                queue.push_back(end);
+            }
+        }
+        Some(node)
+    }))
+}
+
+/// Returns a shortest path from `start` to `end` by hop count, or `None` if `end` is not reachable from `start`.
+///
+/// The path contains both `start` and `end`.
+/// If `start` equals `end`, the path consists of just that node.
+///
+/// Implemented as a breadth-first search that records the predecessor of each discovered node.
+pub fn shortest_path_unweighted<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    end: NodeId,
+) -> Option<Vec<NodeId>> {
+    assert!(graph.is_node_id_valid(start));
+    assert!(graph.is_node_id_valid(end));
+
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut visited = NodeBitset::new(graph.node_len());
+    let mut predecessors = vec![NodeId::invalid(); node_len];
+    let mut queue = VecDeque::new();
+
+    visited.set(start);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if node == end {
+            let mut path = vec![end];
+            let mut current = end;
+            while current != start {
+                current = predecessors[<usize>::from(current)];
+                path.push(current);
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for edge_id in graph.out_edges(node) {
+            let neighbor = graph.edge_end(edge_id);
+            if !visited.contains(neighbor) {
+                visited.set(neighbor);
+                predecessors[<usize>::from(neighbor)] = node;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the predecessor of each node in the breadth-first search tree rooted at `start`, indexed by node id.
+///
+/// The entry of `start` and of all nodes that are not reachable from `start` is `None`.
+/// Following the predecessors from any reachable node leads back to `start` along a shortest path by hop count.
+pub fn bfs_tree<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+) -> Vec<Option<NodeId>> {
+    assert!(graph.is_node_id_valid(start));
+
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut visited = NodeBitset::new(graph.node_len());
+    let mut predecessors = vec![None; node_len];
+    let mut queue = VecDeque::new();
+
+    visited.set(start);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        for edge_id in graph.out_edges(node) {
+            let neighbor = graph.edge_end(edge_id);
+            if !visited.contains(neighbor) {
+                visited.set(neighbor);
+                predecessors[<usize>::from(neighbor)] = Some(node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    predecessors
+}
+
+/// Returns the amount of edges going from `start` to `end`.
+///
+/// Implemented by scanning the out-edges of `start`.
+pub fn edge_multiplicity<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    end: NodeId,
+) -> IdType {
+    assert!(graph.is_node_id_valid(end));
+    let count = graph
+        .out_edges(start)
+        .filter(|&edge_id| graph.edge_end(edge_id) == end)
+        .count();
+    count.try_into().expect("Edge count out of range")
+}
+
+/// Returns the out-degree of each node, indexed by node id.
+pub fn out_degree_sequence<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<IdType> {
+    graph
+        .node_id_iter()
+        .map(|id| {
+            graph
+                .out_edges(id)
+                .count()
+                .try_into()
+                .expect("Out-degree out of range")
+        })
+        .collect()
+}
+
+/// Returns the maximum out-degree of all nodes, or zero if the graph has no nodes.
+pub fn max_out_degree<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> IdType {
+    out_degree_sequence(graph).into_iter().max().unwrap_or(0)
+}
+
+/// Returns the average out-degree of all nodes, or zero if the graph has no nodes.
+///
+/// This only divides the amount of edges by the amount of nodes, without iterating the out-edges.
+pub fn average_out_degree<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> f64 {
+    if graph.node_len() == 0 {
+        0.0
+    } else {
+        f64::from(graph.edge_len()) / f64::from(graph.node_len())
+    }
+}
+
+/// Returns the in-degree of each node, indexed by node id.
+///
+/// This does not require backward navigation: the out-edges of all nodes are scanned once, counting the in-edges of their end nodes.
+pub fn compute_in_degrees<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<IdType> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut in_degrees = vec![0; node_len];
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            in_degrees[<usize>::from(graph.edge_end(edge_id))] += 1;
+        }
+    }
+    in_degrees
+}
+
+/// Returns an iterator over the ids of all edges whose data satisfies the given predicate.
+///
+/// The edges are returned in edge id order.
+pub fn edges_where<'a, N: 'a, E: 'a, G: Graph<N, E>>(
+    graph: &'a G,
+    predicate: impl Fn(&E) -> bool + 'a,
+) -> impl Iterator<Item = EdgeId> + 'a {
+    graph
+        .edge_id_iter()
+        .filter(move |&id| predicate(graph.edge_data(id)))
+}
+
+/// Returns the line graph of the given graph.
+///
+/// Each edge of the given graph becomes a node of the line graph, with the original edge id as node data.
+/// The node ids of the line graph are the indices of the original edge ids.
+/// There is an edge from the node of edge `e` to the node of edge `f` if `f` starts where `e` ends.
+pub fn line_graph<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> SimpleGraph<EdgeId, ()> {
+    let mut result = SimpleGraph::new();
+    for edge_id in graph.edge_id_iter() {
+        result.add_node(Node::new(edge_id));
+    }
+
+    for edge_id in graph.edge_id_iter() {
+        let start = NodeId::from(<usize>::from(edge_id));
+        for next_edge_id in graph.out_edges(graph.edge_end(edge_id)) {
+            let end = NodeId::from(<usize>::from(next_edge_id));
+            result
+                .add_edge(Edge::new(start, end, ()))
+                .unwrap_or_else(|error| panic!("Line graph edge is invalid: {:?}", error));
+        }
+    }
+
+    result
+}
+
+/// Folds the out-edges of the node identified by the given id into a single value, without allocating.
+pub fn fold_out_edges<'a, N, E, G: ForwardNavigableGraph<'a, N, E>, B>(
+    graph: &'a G,
+    id: NodeId,
+    init: B,
+    f: impl FnMut(B, EdgeId) -> B,
+) -> B {
+    graph.out_edges(id).fold(init, f)
+}
+
+/// Returns all nodes with in-degree zero, in node id order.
+///
+/// Implemented by marking the end node of every edge and returning the unmarked nodes.
+pub fn source_nodes<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> Vec<NodeId> {
+    let mut has_in_edge = NodeBitset::new(graph.node_len());
+    for edge_id in graph.edge_id_iter() {
+        has_in_edge.set(graph.edge_end(edge_id));
+    }
+    graph
+        .node_id_iter()
+        .filter(|&id| !has_in_edge.contains(id))
+        .collect()
+}
+
+/// Returns all nodes with out-degree zero, in node id order.
+pub fn sink_nodes<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> Vec<NodeId> {
+    graph
+        .node_id_iter()
+        .filter(|&id| graph.out_edges(id).next().is_none())
+        .collect()
+}
+
+/// Returns the sum of the out-edge weights of each node, indexed by node id.
+///
+/// The weight of an edge is computed from its data by the given function.
+/// Nodes without out-edges have a strength of zero.
+pub fn out_strength<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    weight: impl Fn(&E) -> f64,
+) -> Vec<f64> {
+    graph
+        .node_id_iter()
+        .map(|id| {
+            graph
+                .out_edges(id)
+                .map(|edge_id| weight(graph.edge_data(edge_id)))
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns the cost and a shortest path from `start` to `goal`, or `None` if `goal` is not reachable from `start`.
+///
+/// The cost of an edge is computed from its data by the given `weight` function.
+/// The `heuristic` estimates the cost of reaching `goal` from the given node.
+/// It must be admissible, i.e. it must never overestimate the actual cost, otherwise the returned path may not be shortest.
+/// With a heuristic that always returns zero, this is Dijkstra's algorithm.
+///
+/// The path contains both `start` and `goal`.
+pub fn astar<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    goal: NodeId,
+    weight: impl Fn(&E) -> u64,
+    heuristic: impl Fn(NodeId) -> u64,
+) -> Option<(u64, Vec<NodeId>)> {
+    best_first_search(graph, start, goal, 0, weight, heuristic)
+}
+
+/// Returns the cost and a shortest path from `start` to `goal`, or `None` if `goal` is not reachable from `start`.
+///
+/// The cost of an edge is its `EdgeWeight`, which must not be negative.
+/// The path contains both `start` and `goal`.
+pub fn dijkstra<'a, N, E: EdgeWeight, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    goal: NodeId,
+) -> Option<(f64, Vec<NodeId>)> {
+    astar_edge_weight(graph, start, goal, |_| 0.0)
+}
+
+/// Returns the cost and a shortest path from `start` to `goal`, or `None` if `goal` is not reachable from `start`.
+///
+/// Like `astar`, but the cost of an edge is its `EdgeWeight`, which must not be negative.
+/// The `heuristic` must be admissible, otherwise the returned path may not be shortest.
+/// With a heuristic that always returns zero, this is `dijkstra`.
+///
+/// The path contains both `start` and `goal`.
+pub fn astar_edge_weight<'a, N, E: EdgeWeight, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    goal: NodeId,
+    heuristic: impl Fn(NodeId) -> f64,
+) -> Option<(f64, Vec<NodeId>)> {
+    best_first_search(
+        graph,
+        start,
+        goal,
+        TotalF64(0.0),
+        |data| TotalF64(data.weight()),
+        |id| TotalF64(heuristic(id)),
+    )
+    .map(|(TotalF64(cost), path)| (cost, path))
+}
+
+/// The search behind `astar` and `astar_edge_weight`, generic over the cost type.
+fn best_first_search<
+    'a,
+    N,
+    E,
+    G: ForwardNavigableGraph<'a, N, E>,
+    C: Copy + Ord + Add<Output = C>,
+>(
+    graph: &'a G,
+    start: NodeId,
+    goal: NodeId,
+    zero: C,
+    weight: impl Fn(&E) -> C,
+    heuristic: impl Fn(NodeId) -> C,
+) -> Option<(C, Vec<NodeId>)> {
+    assert!(graph.is_node_id_valid(start));
+    assert!(graph.is_node_id_valid(goal));
+
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut settled = NodeBitset::new(graph.node_len());
+    let mut distances = vec![None; node_len];
+    let mut predecessors = vec![NodeId::invalid(); node_len];
+    let mut queue = BinaryHeap::new();
+
+    distances[<usize>::from(start)] = Some(zero);
+    queue.push(Reverse((heuristic(start), start)));
+
+    while let Some(Reverse((_, node))) = queue.pop() {
+        if settled.contains(node) {
+            continue;
+        }
+        settled.set(node);
+
+        let distance = distances[<usize>::from(node)].expect("Queued node has no distance");
+        if node == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while current != start {
+                current = predecessors[<usize>::from(current)];
+                path.push(current);
+            }
+            path.reverse();
+            return Some((distance, path));
+        }
+
+        for edge_id in graph.out_edges(node) {
+            let neighbor = graph.edge_end(edge_id);
+            if settled.contains(neighbor) {
+                continue;
+            }
+            let neighbor_distance = distance + weight(graph.edge_data(edge_id));
+            let neighbor_index: usize = neighbor.into();
+            if distances[neighbor_index].map_or(true, |old| neighbor_distance < old) {
+                distances[neighbor_index] = Some(neighbor_distance);
+                predecessors[neighbor_index] = node;
+                queue.push(Reverse((neighbor_distance + heuristic(neighbor), neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+/// A totally ordered `f64`, such that it can be used as cost in `best_first_search`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TotalF64(f64);
+
+impl Eq for TotalF64 {}
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Add for TotalF64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        TotalF64(self.0 + other.0)
+    }
+}
+
+/// Returns groups of parallel edges, i.e. edges that share the same start and end node.
+///
+/// Only groups with at least two edges are returned.
+/// The groups are ordered by their start and end node, and the edges in each group by their id.
+pub fn parallel_edge_groups<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<Vec<EdgeId>> {
+    let mut edges: Vec<_> = graph
+        .edge_id_iter()
+        .map(|id| (graph.edge_start(id), graph.edge_end(id), id))
+        .collect();
+    edges.sort_unstable();
+
+    edges
+        .chunk_by(|a, b| (a.0, a.1) == (b.0, b.1))
+        .filter(|group| group.len() >= 2)
+        .map(|group| group.iter().map(|&(_, _, id)| id).collect())
+        .collect()
+}
+
+/// Returns the transitive closure of the given graph.
+///
+/// Entry `i` of the result is the set of nodes reachable from the node with id `i`, including the node itself.
+/// Implemented as one breadth-first search per node, so this is only suitable for small graphs.
+pub fn transitive_closure<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<NodeBitset> {
+    graph
+        .node_id_iter()
+        .map(|root| multi_source_reachable(graph, &[root]))
+        .collect()
+}
+
+/// Returns the set of nodes reachable from any of the given sources, including the sources themselves.
+///
+/// Implemented as a single breadth-first search that starts from all sources at once.
+pub fn multi_source_reachable<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    sources: &[NodeId],
+) -> NodeBitset {
+    let mut reachable = NodeBitset::new(graph.node_len());
+    let mut queue = VecDeque::new();
+    for &source in sources {
+        if !reachable.contains(source) {
+            reachable.set(source);
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        for edge_id in graph.out_edges(node) {
+            let neighbor = graph.edge_end(edge_id);
+            if !reachable.contains(neighbor) {
+                reachable.set(neighbor);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    reachable
+}
+
+/// Returns the id of the first node whose data satisfies the given predicate, or `None` if there is no such node.
+///
+/// This scans all nodes in node id order and therefore takes `O(n)` time.
+pub fn find_node<N, E, G: Graph<N, E>>(
+    graph: &G,
+    predicate: impl Fn(&N) -> bool,
+) -> Option<NodeId> {
+    graph
+        .node_id_iter()
+        .find(|&id| predicate(graph.node_data(id)))
+}
+
+/// Returns a copy of the given graph in which all parallel edges are merged into a single edge.
+///
+/// The data of parallel edges is folded with `combine`, in the order of the out-edges of their start node.
+/// Node ids are preserved, and the merged edges are ordered by start node and by the first occurrence of each end node.
+/// Self-loops are merged like any other edges.
+pub fn merge_parallel_edges<'a, N: Clone, E: Clone, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    combine: impl Fn(&E, &E) -> E,
+) -> SimpleGraph<N, E> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut result = SimpleGraph::new();
+    for id in graph.node_id_iter() {
+        result.add_node(Node::new(graph.node_data(id).clone()));
+    }
+
+    let mut merged_index: Vec<Option<usize>> = vec![None; node_len];
+    let mut merged_edges: Vec<(NodeId, E)> = Vec::new();
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            let end = graph.edge_end(edge_id);
+            let data = graph.edge_data(edge_id);
+            match merged_index[<usize>::from(end)] {
+                Some(index) => {
+                    let merged = &mut merged_edges[index].1;
+                    *merged = combine(merged, data);
+                },
+                None => {
+                    merged_index[<usize>::from(end)] = Some(merged_edges.len());
+                    merged_edges.push((end, data.clone()));
+                },
+            }
+        }
+
+        for (end, data) in merged_edges.drain(..) {
+            merged_index[<usize>::from(end)] = None;
+            result
+                .add_edge(Edge::new(start, end, data))
+                .unwrap_or_else(|error| panic!("Merged edge is invalid: {:?}", error));
+        }
+    }
+
+    result
+}
+
+/// Returns the index of the strongly connected component of each node, indexed by node id.
+///
+/// Components are numbered in topological order of the condensation,
+/// i.e. every edge between two different components leads from a lower to a higher component index.
+///
+/// Implemented as an iterative version of Tarjan's algorithm.
+pub fn strongly_connected_components<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<usize> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut index: Vec<Option<usize>> = vec![None; node_len];
+    let mut lowlink = vec![0; node_len];
+    let mut on_stack = NodeBitset::new(graph.node_len());
+    let mut component = vec![0; node_len];
+    let mut component_len = 0;
+    let mut next_index = 0;
+    let mut stack = Vec::new();
+    let mut call_stack = Vec::new();
+
+    for root in graph.node_id_iter() {
+        if index[<usize>::from(root)].is_some() {
+            continue;
+        }
+
+        index[<usize>::from(root)] = Some(next_index);
+        lowlink[<usize>::from(root)] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack.set(root);
+        call_stack.push((root, graph.out_edges(root)));
+
+        while let Some((node, out_edges)) = call_stack.last_mut() {
+            let node = *node;
+            if let Some(edge_id) = out_edges.next() {
+                let end = graph.edge_end(edge_id);
+                match index[<usize>::from(end)] {
+                    None => {
+                        index[<usize>::from(end)] = Some(next_index);
+                        lowlink[<usize>::from(end)] = next_index;
+                        next_index += 1;
+                        stack.push(end);
+                        on_stack.set(end);
+                        call_stack.push((end, graph.out_edges(end)));
+                    },
+                    Some(end_index) if on_stack.contains(end) => {
+                        let low = &mut lowlink[<usize>::from(node)];
+                        *low = (*low).min(end_index);
+                    },
+                    Some(_) => {},
+                }
+            } else {
+                call_stack.pop();
+                let node_lowlink = lowlink[<usize>::from(node)];
+                if let Some((parent, _)) = call_stack.last() {
+                    let low = &mut lowlink[<usize>::from(*parent)];
+                    *low = (*low).min(node_lowlink);
+                }
+
+                if Some(node_lowlink) == index[<usize>::from(node)] {
+                    while let Some(member) = stack.pop() {
+                        on_stack.clear(member);
+                        component[<usize>::from(member)] = component_len;
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component_len += 1;
+                }
+            }
+        }
+    }
+
+    // Tarjan's algorithm finishes components in reverse topological order.
+    for component in &mut component {
+        *component = component_len - 1 - *component;
+    }
+    component
+}
+
+/// Returns the condensation of the given graph, together with the component index of each original node.
+///
+/// Each strongly connected component becomes a single node whose data are the ids of its members, in node id order.
+/// The components are numbered as in `strongly_connected_components`, so the node ids of the condensation are in topological order.
+/// Edges between two different components are merged into a single edge, edges within a component are dropped.
+pub fn condensation<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> (SimpleGraph<Vec<NodeId>, ()>, Vec<usize>) {
+    let component = strongly_connected_components(graph);
+    let component_len = component.iter().map(|&c| c + 1).max().unwrap_or(0);
+    let mut members = vec![Vec::new(); component_len];
+    for id in graph.node_id_iter() {
+        members[component[<usize>::from(id)]].push(id);
+    }
+
+    let mut result = SimpleGraph::new();
+    let mut last_seen: Vec<Option<usize>> = vec![None; component_len];
+    let mut edges = Vec::new();
+    for (start, component_members) in members.iter().enumerate() {
+        for &node in component_members {
+            for edge_id in graph.out_edges(node) {
+                let end = component[<usize>::from(graph.edge_end(edge_id))];
+                if end != start && last_seen[end] != Some(start) {
+                    last_seen[end] = Some(start);
+                    edges.push((start, end));
+                }
+            }
+        }
+    }
+
+    for component_members in members {
+        result.add_node(Node::new(component_members));
+    }
+    for (start, end) in edges {
+        result
+            .add_edge(Edge::new(NodeId::from(start), NodeId::from(end), ()))
+            .unwrap_or_else(|error| panic!("Condensation edge is invalid: {:?}", error));
+    }
+
+    (result, component)
+}
+
+/// Returns the diameter of the given graph, i.e. the largest hop distance between any node and a node reachable from it.
+/// Returns `None` if the graph is empty.
+///
+/// Pairs of nodes where one is not reachable from the other are ignored, so a graph without edges has diameter zero.
+///
+/// Implemented as a breadth-first search from each node, which takes O(V·(V+E)) time.
+pub fn diameter<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> Option<IdType> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut distances: Vec<Option<IdType>> = vec![None; node_len];
+    let mut queue = VecDeque::new();
+    let mut result = None;
+
+    for root in graph.node_id_iter() {
+        distances.iter_mut().for_each(|distance| *distance = None);
+        distances[<usize>::from(root)] = Some(0);
+        queue.push_back(root);
+        let mut eccentricity = 0;
+
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[<usize>::from(node)].expect("Queued node has no distance");
+            eccentricity = distance;
+            for edge_id in graph.out_edges(node) {
+                let neighbor = graph.edge_end(edge_id);
+                if distances[<usize>::from(neighbor)].is_none() {
+                    distances[<usize>::from(neighbor)] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        result = result.max(Some(eccentricity));
+    }
+
+    result
+}
+
+/// Returns an iterator over the ids of all edges that start inside the given set of nodes and end outside of it.
+///
+/// Implemented by scanning the out-edges of the members of the set.
+/// The edges are returned ordered by start node, and in the order of `out_edges` for each start node.
+pub fn cut_edges<'a, N: 'a, E: 'a, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    set: &'a NodeBitset,
+) -> impl Iterator<Item = EdgeId> + 'a {
+    graph
+        .node_id_iter()
+        .filter(move |&id| set.contains(id))
+        .flat_map(move |id| graph.out_edges(id))
+        .filter(move |&edge_id| !set.contains(graph.edge_end(edge_id)))
+}
+
+/// Returns the complement of the given graph.
+///
+/// The complement has the same nodes, and an edge from `u` to `v` for every pair of distinct nodes
+/// such that the given graph has no edge from `u` to `v`. Self-loops are never added.
+/// Node ids are preserved, and the edges are ordered by start node and then by end node.
+///
+/// This takes O(V²) time and creates up to V·(V-1) edges.
+pub fn complement<'a, N: Clone, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> SimpleGraph<N, ()> {
+    let mut result = SimpleGraph::new();
+    for id in graph.node_id_iter() {
+        result.add_node(Node::new(graph.node_data(id).clone()));
+    }
+
+    let mut neighbors = NodeBitset::new(graph.node_len());
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            neighbors.set(graph.edge_end(edge_id));
+        }
+
+        for end in graph.node_id_iter() {
+            if end != start && !neighbors.contains(end) {
+                result
+                    .add_edge(Edge::new(start, end, ()))
+                    .unwrap_or_else(|error| panic!("Complement edge is invalid: {:?}", error));
+            }
+        }
+
+        for edge_id in graph.out_edges(start) {
+            neighbors.clear(graph.edge_end(edge_id));
+        }
+    }
+
+    result
+}
+
+/// Returns the value of a maximum flow from `source` to `sink`, where the capacity of each edge is computed by `capacity`.
+/// Returns zero if `source` equals `sink`.
+///
+/// Implemented with the Edmonds-Karp algorithm, which repeatedly augments along a shortest path in the residual graph.
+/// The residual graph is built internally, with a reverse arc of capacity zero for every edge.
+/// This takes O(V·E²) time.
+pub fn max_flow<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    source: NodeId,
+    sink: NodeId,
+    capacity: impl Fn(&E) -> u64,
+) -> u64 {
+    if source == sink {
+        return 0;
+    }
+
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    // Arc 2i is the forward arc of the i-th edge, arc 2i + 1 its reverse arc.
+    let mut arc_heads = Vec::new();
+    let mut residuals = Vec::new();
+    let mut out_arcs = vec![Vec::new(); node_len];
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            let end = graph.edge_end(edge_id);
+            out_arcs[<usize>::from(start)].push(arc_heads.len());
+            arc_heads.push(end);
+            residuals.push(capacity(graph.edge_data(edge_id)));
+            out_arcs[<usize>::from(end)].push(arc_heads.len());
+            arc_heads.push(start);
+            residuals.push(0);
+        }
+    }
+
+    let mut flow = 0;
+    let mut predecessor_arcs: Vec<Option<usize>> = vec![None; node_len];
+    let mut queue = VecDeque::new();
+    loop {
+        predecessor_arcs.iter_mut().for_each(|arc| *arc = None);
+        let mut visited = NodeBitset::new(graph.node_len());
+        visited.set(source);
+        queue.clear();
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            if node == sink {
+                break;
+            }
+            for &arc in &out_arcs[<usize>::from(node)] {
+                let head = arc_heads[arc];
+                if residuals[arc] > 0 && !visited.contains(head) {
+                    visited.set(head);
+                    predecessor_arcs[<usize>::from(head)] = Some(arc);
+                    queue.push_back(head);
+                }
+            }
+        }
+
+        if !visited.contains(sink) {
+            return flow;
+        }
+
+        // The tail of an arc is the head of its partner arc.
+        let path_arcs = || {
+            core::iter::successors(predecessor_arcs[<usize>::from(sink)], |&arc| {
+                predecessor_arcs[<usize>::from(arc_heads[arc ^ 1])]
+            })
+        };
+        let bottleneck = path_arcs()
+            .map(|arc| residuals[arc])
+            .min()
+            .expect("Augmenting path is empty");
+        for arc in path_arcs().collect::<Vec<_>>() {
+            residuals[arc] -= bottleneck;
+            residuals[arc ^ 1] += bottleneck;
+        }
+        flow += bottleneck;
+    }
+}
+
+/// Returns the ids of all edges of the given graph, sorted ascending by their edge data.
+///
+/// The sort is stable, so edges with equal data stay in edge id order.
+pub fn edge_ids_by_data<N, E: Ord, G: Graph<N, E>>(graph: &G) -> Vec<EdgeId> {
+    let mut edge_ids: Vec<_> = graph.edge_id_iter().collect();
+    edge_ids.sort_by_key(|&id| graph.edge_data(id));
+    edge_ids
+}
+
+/// Returns the edges of a minimum spanning forest of the given graph, where the weight of each edge is computed by `weight`.
+///
+/// Edges are treated as undirected. If the graph is connected, the result is a minimum spanning tree.
+/// The edges are returned in the order in which they are added to the forest, i.e. by increasing weight,
+/// and edges with equal weight are considered in edge id order.
+///
+/// Implemented with Kruskal's algorithm using a union-find structure.
+pub fn minimum_spanning_tree<N, E, G: Graph<N, E>>(
+    graph: &G,
+    weight: impl Fn(&E) -> u64,
+) -> Vec<EdgeId> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut edge_ids: Vec<_> = graph.edge_id_iter().collect();
+    edge_ids.sort_by_key(|&id| weight(graph.edge_data(id)));
+
+    let mut components = UnionFind::new(node_len);
+    edge_ids
+        .into_iter()
+        .filter(|&id| {
+            let (start, end) = graph.edge_endpoints(id);
+            components.union(start.into(), end.into())
+        })
+        .collect()
+}
+
+/// Returns the number of weakly connected components of the given graph.
+///
+/// Edges are treated as undirected, and isolated nodes form components of their own.
+/// Implemented with a union-find structure, so no component labels are computed.
+pub fn connected_component_count<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> usize {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut components = UnionFind::new(node_len);
+    let mut component_count = node_len;
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            if components.union(start.into(), graph.edge_end(edge_id).into()) {
+                component_count -= 1;
+            }
+        }
+    }
+    component_count
+}
+
+/// Splits the given graph into one graph per weakly connected component.
+///
+/// Edges are treated as undirected when determining the components.
+/// The components are ordered by their smallest node id, and the node ids are renumbered within each component.
+/// Nodes and edges keep their relative order, so the nodes and edges of each component are ordered as in the source graph.
+pub fn split_components<'a, N: Clone, E: Clone, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<SimpleGraph<N, E>> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut components = UnionFind::new(node_len);
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            components.union(start.into(), graph.edge_end(edge_id).into());
+        }
+    }
+
+    let mut result: Vec<SimpleGraph<N, E>> = Vec::new();
+    let mut component_indices = vec![None; node_len];
+    let mut node_map = Vec::with_capacity(node_len);
+    for id in graph.node_id_iter() {
+        let root = components.find(id.into());
+        let component = *component_indices[root].get_or_insert_with(|| {
+            result.push(SimpleGraph::new());
+            result.len() - 1
+        });
+        let new_id = result[component].add_node(Node::new(graph.node_data(id).clone()));
+        node_map.push((component, new_id));
+    }
+
+    for edge_id in graph.edge_id_iter() {
+        let (start, end) = graph.edge_endpoints(edge_id);
+        let (component, start) = node_map[<usize>::from(start)];
+        let (_, end) = node_map[<usize>::from(end)];
+        result[component]
+            .add_edge(Edge::new(start, end, graph.edge_data(edge_id).clone()))
+            .unwrap_or_else(|error| panic!("Remapped edge is invalid: {:?}", error));
+    }
+
+    result
+}
+
+/// A union-find structure over the indices `0..len`, with union by size and path halving.
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    /// Returns false if they were already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            core::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        true
+    }
+}
+
+/// Returns a 2-coloring of the given graph, or `None` if the graph is not bipartite.
+///
+/// Edges are treated as undirected. The coloring contains one entry per node, indexed by node id,
+/// such that the endpoints of every edge have different colors.
+/// The first node of each connected component is colored `false`.
+/// A self-loop makes a graph non-bipartite.
+///
+/// Implemented as a breadth-first search over the undirected interpretation of the graph.
+pub fn bipartite_coloring<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Option<Vec<bool>> {
+    let neighbors = undirected_adjacency_lists(graph);
+    let mut colors: Vec<Option<bool>> = vec![None; neighbors.len()];
+    let mut queue = VecDeque::new();
+
+    for root in graph.node_id_iter() {
+        if colors[<usize>::from(root)].is_some() {
+            continue;
+        }
+
+        colors[<usize>::from(root)] = Some(false);
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front() {
+            let color = colors[<usize>::from(node)].expect("Queued node has no color");
+            for &neighbor in &neighbors[<usize>::from(node)] {
+                match colors[<usize>::from(neighbor)] {
+                    None => {
+                        colors[<usize>::from(neighbor)] = Some(!color);
+                        queue.push_back(neighbor);
+                    },
+                    Some(neighbor_color) if neighbor_color == color => return None,
+                    Some(_) => {},
+                }
+            }
+        }
+    }
+
+    Some(
+        colors
+            .into_iter()
+            .map(|color| color.expect("Node was not colored"))
+            .collect(),
+    )
+}
+
+/// Returns the adjacency of the undirected interpretation of the given graph as one vector of neighbors per node.
+///
+/// Each edge is listed at both of its endpoints, and a self-loop is listed twice at its node.
+fn undirected_adjacency_lists<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<Vec<NodeId>> {
+    let mut neighbors = adjacency_lists(graph);
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            neighbors[<usize>::from(graph.edge_end(edge_id))].push(start);
+        }
+    }
+    neighbors
+}
+
+/// Returns the PageRank of each node, indexed by node id.
+///
+/// Starting from a uniform distribution, each of the given amount of iterations distributes a `damping` fraction
+/// of the rank of each node evenly over its out-edges, and the remaining fraction evenly over all nodes.
+/// The rank of dangling nodes, i.e. nodes without out-edges, is distributed evenly over all nodes.
+/// The scores sum up to approximately one.
+pub fn pagerank<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    damping: f64,
+    iterations: usize,
+) -> Vec<f64> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    if node_len == 0 {
+        return Vec::new();
+    }
+
+    let uniform = 1.0 / node_len as f64;
+    let mut ranks = vec![uniform; node_len];
+    let mut next_ranks = vec![0.0; node_len];
+    for _ in 0..iterations {
+        let mut dangling_rank = 0.0;
+        next_ranks.iter_mut().for_each(|rank| *rank = 0.0);
+        for start in graph.node_id_iter() {
+            let rank = ranks[<usize>::from(start)];
+            let out_degree = graph.out_edges(start).count();
+            if out_degree == 0 {
+                dangling_rank += rank;
+            } else {
+                let share = rank / out_degree as f64;
+                for edge_id in graph.out_edges(start) {
+                    next_ranks[<usize>::from(graph.edge_end(edge_id))] += share;
+                }
+            }
+        }
+
+        let base = (1.0 - damping) * uniform + damping * dangling_rank * uniform;
+        for rank in &mut next_ranks {
+            *rank = base + damping * *rank;
+        }
+        core::mem::swap(&mut ranks, &mut next_ranks);
+    }
+
+    ranks
+}
+
+/// Returns the betweenness centrality of each node, indexed by node id.
+///
+/// The betweenness centrality of a node `v` is the sum over all ordered pairs of distinct nodes `s` and `t`, both different from `v`,
+/// of the fraction of shortest paths from `s` to `t` that pass through `v`.
+/// Edges are directed and unweighted, and parallel edges count as distinct paths.
+/// For a graph that models an undirected graph by inserting each edge in both directions, the scores are twice the undirected scores.
+///
+/// Implemented with Brandes' algorithm, which takes O(VE) time.
+pub fn betweenness_centrality<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<f64> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut centrality = vec![0.0; node_len];
+    let mut distances = vec![None; node_len];
+    let mut path_counts = vec![0.0; node_len];
+    let mut dependencies = vec![0.0; node_len];
+    let mut predecessors = vec![Vec::new(); node_len];
+    let mut order = Vec::with_capacity(node_len);
+    let mut queue = VecDeque::new();
+
+    for source in graph.node_id_iter() {
+        let source_index = <usize>::from(source);
+        distances.iter_mut().for_each(|distance| *distance = None);
+        path_counts.iter_mut().for_each(|count| *count = 0.0);
+        dependencies
+            .iter_mut()
+            .for_each(|dependency| *dependency = 0.0);
+        predecessors.iter_mut().for_each(Vec::clear);
+        order.clear();
+
+        distances[source_index] = Some(0);
+        path_counts[source_index] = 1.0;
+        queue.push_back(source);
+        while let Some(node) = queue.pop_front() {
+            let node_index = <usize>::from(node);
+            order.push(node_index);
+            let next_distance = distances[node_index].map(|distance: usize| distance + 1);
+            for edge_id in graph.out_edges(node) {
+                let end = graph.edge_end(edge_id);
+                let end_index = <usize>::from(end);
+                if distances[end_index].is_none() {
+                    distances[end_index] = next_distance;
+                    queue.push_back(end);
+                }
+                if distances[end_index] == next_distance {
+                    path_counts[end_index] += path_counts[node_index];
+                    predecessors[end_index].push(node_index);
+                }
+            }
+        }
+
+        for &node_index in order.iter().rev() {
+            let share = (1.0 + dependencies[node_index]) / path_counts[node_index];
+            for &predecessor in &predecessors[node_index] {
+                dependencies[predecessor] += path_counts[predecessor] * share;
+            }
+            if node_index != source_index {
+                centrality[node_index] += dependencies[node_index];
+            }
+        }
+    }
+
+    centrality
+}
+
+/// Returns the number of distinct triangles in the given graph.
+///
+/// Edges are treated as undirected, and self-loops and parallel edges are ignored.
+/// A triangle is a set of three nodes that are pairwise adjacent.
+///
+/// Implemented by intersecting the sorted lists of higher neighbors of the endpoints of each edge,
+/// such that each triangle is counted exactly once at its lowest node.
+pub fn count_triangles<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> u64 {
+    let higher_neighbors: Vec<Vec<NodeId>> = simple_undirected_neighbors(graph)
+        .into_iter()
+        .enumerate()
+        .map(|(node_index, neighbors)| {
+            neighbors
+                .into_iter()
+                .filter(|&neighbor| <usize>::from(neighbor) > node_index)
+                .collect()
+        })
+        .collect();
+
+    higher_neighbors
+        .iter()
+        .map(|neighbors| {
+            neighbors
+                .iter()
+                .map(|&neighbor| {
+                    sorted_intersection_len(neighbors, &higher_neighbors[<usize>::from(neighbor)])
+                })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Returns the neighbors of each node in the undirected interpretation of the given graph, sorted by node id.
+/// Self-loops and parallel edges are removed.
+fn simple_undirected_neighbors<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<Vec<NodeId>> {
+    let mut neighbors = undirected_adjacency_lists(graph);
+    for (node_index, neighbors) in neighbors.iter_mut().enumerate() {
+        neighbors.retain(|&neighbor| <usize>::from(neighbor) != node_index);
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+    neighbors
+}
+
+/// Returns the amount of common elements of the two given sorted slices without duplicates.
+fn sorted_intersection_len(a: &[NodeId], b: &[NodeId]) -> u64 {
+    let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+    let mut len = 0;
+    while let (Some(&x), Some(&y)) = (a.peek(), b.peek()) {
+        match x.cmp(y) {
+            Ordering::Less => {
+                a.next();
+            },
+            Ordering::Greater => {
+                b.next();
+            },
+            Ordering::Equal => {
+                len += 1;
+                a.next();
+                b.next();
+            },
+        }
+    }
+    len
+}
+
+/// Returns the local clustering coefficient of each node, indexed by node id.
+///
+/// Edges are treated as undirected, and self-loops and parallel edges are ignored.
+/// The clustering coefficient of a node is the amount of triangles through the node,
+/// divided by the amount of pairs of its neighbors. Nodes with less than two neighbors have a coefficient of zero.
+pub fn clustering_coefficients<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+) -> Vec<f64> {
+    let neighbors = simple_undirected_neighbors(graph);
+    neighbors
+        .iter()
+        .map(|node_neighbors| {
+            let degree = node_neighbors.len() as u64;
+            if degree < 2 {
+                return 0.0;
+            }
+
+            // Each edge between two neighbors is found from both of its endpoints.
+            let triangles = node_neighbors
+                .iter()
+                .map(|&neighbor| {
+                    sorted_intersection_len(node_neighbors, &neighbors[<usize>::from(neighbor)])
+                })
+                .sum::<u64>()
+                / 2;
+            triangles as f64 / (degree * (degree - 1) / 2) as f64
+        })
+        .collect()
+}
+
+/// Returns a path from `start` to `goal` with at most `max_depth` edges, or `None` if there is no such path.
+///
+/// The path contains both `start` and `goal`, and has the smallest possible amount of edges.
+/// If `start` equals `goal`, the path consists of just that node.
+///
+/// Implemented as an iterative deepening depth-first search, which runs a depth-limited search with increasing limits.
+/// Nodes on the current path are not revisited, and the search stops early if a limit was not reached by any path.
+pub fn iddfs<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    goal: NodeId,
+    max_depth: usize,
+) -> Option<Vec<NodeId>> {
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    for limit in 1..=max_depth {
+        let mut path = vec![start];
+        let mut stack = vec![graph.out_edges(start)];
+        let mut limit_reached = false;
+
+        while let Some(out_edges) = stack.last_mut() {
+            if let Some(edge_id) = out_edges.next() {
+                let end = graph.edge_end(edge_id);
+                if end == goal {
+                    path.push(end);
+                    return Some(path);
+                }
+                if path.contains(&end) {
+                    continue;
+                }
+                if path.len() == limit {
+                    limit_reached = true;
+                    continue;
+                }
+                path.push(end);
+                stack.push(graph.out_edges(end));
+            } else {
+                stack.pop();
+                path.pop();
+            }
+        }
+
+        if !limit_reached {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// A summary of basic statistics of a graph, as returned by `statistics`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphStats {
+    /// The amount of nodes.
+    pub node_len: IdType,
+    /// The amount of edges.
+    pub edge_len: IdType,
+    /// The maximum out-degree of all nodes, or zero if the graph has no nodes.
+    pub max_out_degree: IdType,
+    /// The minimum out-degree of all nodes, or zero if the graph has no nodes.
+    pub min_out_degree: IdType,
+    /// The average out-degree of all nodes, or zero if the graph has no nodes.
+    pub average_out_degree: f64,
+    /// The amount of edges whose start node equals their end node.
+    pub self_loop_len: IdType,
+    /// The amount of nodes without any incoming or outgoing edges.
+    pub isolated_node_len: IdType,
+}
+
+/// Returns a summary of basic statistics of the given graph.
+///
+/// Computes all statistics in a single pass over the out-edges of all nodes.
+pub fn statistics<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(graph: &'a G) -> GraphStats {
+    let mut has_in_edges = NodeBitset::new(graph.node_len());
+    let mut out_degrees = Vec::new();
+    let mut self_loop_len = 0;
+    for start in graph.node_id_iter() {
+        let mut out_degree = 0;
+        for edge_id in graph.out_edges(start) {
+            let end = graph.edge_end(edge_id);
+            has_in_edges.set(end);
+            if end == start {
+                self_loop_len += 1;
+            }
+            out_degree += 1;
+        }
+        out_degrees.push(out_degree);
+    }
+
+    let isolated_node_len = graph
+        .node_id_iter()
+        .zip(&out_degrees)
+        .filter(|&(id, &out_degree)| out_degree == 0 && !has_in_edges.contains(id))
+        .count()
+        .try_into()
+        .expect("Isolated node len incompatible with IdType");
+    GraphStats {
+        node_len: graph.node_len(),
+        edge_len: graph.edge_len(),
+        max_out_degree: out_degrees.iter().copied().max().unwrap_or(0),
+        min_out_degree: out_degrees.iter().copied().min().unwrap_or(0),
+        average_out_degree: average_out_degree(graph),
+        self_loop_len,
+        isolated_node_len,
+    }
+}
+
+/// Returns the subgraph induced by the nodes for which `keep` returns true.
+///
+/// The kept nodes are added in node id order, and the edges whose endpoints are both kept are added in edge id order.
+/// The second return value maps the node ids of the given graph to their new ids, indexed by the old node id,
+/// with `None` for dropped nodes.
+pub fn filter_nodes<N: Clone, E: Clone, G: Graph<N, E>>(
+    graph: &G,
+    keep: impl Fn(NodeId, &N) -> bool,
+) -> (SimpleGraph<N, E>, Vec<Option<NodeId>>) {
+    let mut result = SimpleGraph::new();
+    let node_map: Vec<_> = graph
+        .node_id_iter()
+        .map(|id| {
+            let data = graph.node_data(id);
+            if keep(id, data) {
+                Some(result.add_node(Node::new(data.clone())))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    for edge_id in graph.edge_id_iter() {
+        let (start, end) = graph.edge_endpoints(edge_id);
+        if let (Some(start), Some(end)) =
+            (node_map[<usize>::from(start)], node_map[<usize>::from(end)])
+        {
+            result
+                .add_edge(Edge::new(start, end, graph.edge_data(edge_id).clone()))
+                .unwrap_or_else(|error| panic!("Filtered edge is invalid: {:?}", error));
+        }
+    }
+
+    (result, node_map)
+}
+
+/// Returns an iterator over the nodes of a random walk starting at `start`.
+///
+/// The first returned node is `start`. In each step, the walk follows an out-edge of the current node chosen uniformly at random.
+/// The iterator ends after returning a node without out-edges, and is infinite if the walk never reaches such a node.
+/// The walk is generated from a seeded pseudo random number generator, such that the same seed always yields the same walk.
+pub fn random_walk<'a, N: 'a, E: 'a, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    seed: u64,
+) -> impl Iterator<Item = NodeId> + 'a {
+    let mut random = XorShift64Star::new(seed);
+    core::iter::successors(Some(start), move |&node| {
+        let out_degree = graph.out_edges(node).count();
+        if out_degree == 0 {
+            return None;
+        }
+        graph
+            .out_edges(node)
+            .nth(random.next_below(out_degree))
+            .map(|edge_id| graph.edge_end(edge_id))
+    })
+}
+
+/// Returns an iterator over all nodes reachable from `start` in breadth-first order, together with their hop distance from `start`.
+///
+/// The first returned node is `start` with distance zero, and the distances are non-decreasing.
+/// The search is performed lazily while the iterator is advanced.
+pub fn bfs_with_distance<'a, N: 'a, E: 'a, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+) -> impl Iterator<Item = (NodeId, IdType)> + 'a {
+    let mut discovered = NodeBitset::new(graph.node_len());
+    discovered.set(start);
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+
+    core::iter::from_fn(move || {
+        let (node, distance) = queue.pop_front()?;
+        for edge_id in graph.out_edges(node) {
+            let neighbor = graph.edge_end(edge_id);
+            if !discovered.contains(neighbor) {
+                discovered.set(neighbor);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+        Some((node, distance))
+    })
+}
+
+/// Returns a copy of the given graph in which the edge identified by the given id is contracted.
+///
+/// The end node of the edge is merged into its start node, which keeps its data.
+/// All edges incident to the end node are redirected to the start node.
+/// The contracted edge and all other edges between its two endpoints are dropped, since they would become self-loops,
+/// while existing self-loops are kept. The remaining edges keep their relative order.
+/// If the contracted edge is a self-loop, only that edge is dropped.
+///
+/// The second return value maps the node ids of the given graph to their new ids, indexed by the old node id.
+/// The nodes keep their relative order.
+pub fn contract_edge<N: Clone, E: Clone, G: Graph<N, E>>(
+    graph: &G,
+    id: EdgeId,
+) -> (SimpleGraph<N, E>, Vec<NodeId>) {
+    let (merged_start, merged_end) = graph.edge_endpoints(id);
+    let mut result = SimpleGraph::new();
+    let mut node_map = Vec::with_capacity(
+        graph
+            .node_len()
+            .try_into()
+            .expect("Node len incompatible with usize"),
+    );
+    for node in graph.node_id_iter() {
+        if node == merged_end && merged_end != merged_start {
+            node_map.push(NodeId::invalid());
+        } else {
+            node_map.push(result.add_node(Node::new(graph.node_data(node).clone())));
+        }
+    }
+    node_map[<usize>::from(merged_end)] = node_map[<usize>::from(merged_start)];
+
+    for edge_id in graph.edge_id_iter() {
+        let (start, end) = graph.edge_endpoints(edge_id);
+        let is_merged_edge = (start == merged_start && end == merged_end)
+            || (start == merged_end && end == merged_start);
+        if edge_id == id || (is_merged_edge && merged_start != merged_end) {
+            continue;
+        }
+        result
+            .add_edge(Edge::new(
+                node_map[<usize>::from(start)],
+                node_map[<usize>::from(end)],
+                graph.edge_data(edge_id).clone(),
+            ))
+            .unwrap_or_else(|error| panic!("Contracted edge is invalid: {:?}", error));
+    }
+
+    (result, node_map)
+}
+
+/// Returns all simple paths from `start` to `end` with at most `max_len` edges.
+///
+/// Each path contains both `start` and `end`, and no node more than once.
+/// If `start` equals `end`, the only path consists of just that node.
+/// The paths are returned in depth-first order, following the out-edges of each node in the order of `out_edges`.
+///
+/// Implemented as a backtracking depth-first search that does not revisit nodes on the current path.
+/// The amount of simple paths can be exponential in the size of the graph, so `max_len` should be chosen small enough
+/// to bound the search.
+pub fn all_simple_paths<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    start: NodeId,
+    end: NodeId,
+    max_len: usize,
+) -> Vec<Vec<NodeId>> {
+    if start == end {
+        return vec![vec![start]];
+    }
+
+    let mut paths = Vec::new();
+    let mut path = vec![start];
+    let mut stack = vec![graph.out_edges(start)];
+    while let Some(out_edges) = stack.last_mut() {
+        if path.len() > max_len {
+            stack.pop();
+            path.pop();
+            continue;
+        }
+
+        if let Some(edge_id) = out_edges.next() {
+            let next = graph.edge_end(edge_id);
+            if next == end {
+                let mut found = path.clone();
+                found.push(next);
+                paths.push(found);
+            } else if !path.contains(&next) {
+                path.push(next);
+                stack.push(graph.out_edges(next));
+            }
+        } else {
+            stack.pop();
+            path.pop();
+        }
+    }
+
+    paths
+}
+
+/// Returns the matrix of shortest path distances between all pairs of nodes, where the weight of each edge is computed by `weight`.
+///
+/// The entry `[i][j]` is the distance from the node with index `i` to the node with index `j`,
+/// or `None` if the latter is not reachable from the former. The distance from each node to itself is zero.
+/// Negative edge weights are supported. If a node lies on a cycle with negative total weight,
+/// its diagonal entry is `None`, and the distances of all paths that can use that cycle are undefined.
+/// A distance that overflows below `i64::MIN` is treated the same way, as if its path used a negative cycle,
+/// while a distance that overflows above `i64::MAX` saturates to `i64::MAX`.
+///
+/// Implemented with the Floyd-Warshall algorithm, which takes O(V³) time and O(V²) memory.
+pub fn floyd_warshall<'a, N, E, G: ForwardNavigableGraph<'a, N, E>>(
+    graph: &'a G,
+    weight: impl Fn(&E) -> i64,
+) -> Vec<Vec<Option<i64>>> {
+    let node_len: usize = graph
+        .node_len()
+        .try_into()
+        .expect("Node len incompatible with usize");
+    let mut distances = vec![vec![None; node_len]; node_len];
+    for (node_index, row) in distances.iter_mut().enumerate() {
+        row[node_index] = Some(0);
+    }
+    for start in graph.node_id_iter() {
+        for edge_id in graph.out_edges(start) {
+            let entry =
+                &mut distances[<usize>::from(start)][<usize>::from(graph.edge_end(edge_id))];
+            let edge_weight = weight(graph.edge_data(edge_id));
+            *entry = Some(entry.map_or(edge_weight, |distance| distance.min(edge_weight)));
+        }
+    }
+
+    for via in 0..node_len {
+        let via_row = distances[via].clone();
+        for row in &mut distances {
+            let to_via = match row[via] {
+                Some(distance) => distance,
+                None => continue,
+            };
+            for (entry, from_via) in row.iter_mut().zip(&via_row) {
+                if let Some(from_via) = from_via {
+                    // Saturating to i64::MIN poisons the entry like a negative cycle,
+                    // while saturating to i64::MAX never beats a finite distance.
+                    let candidate = to_via.saturating_add(*from_via);
+                    if entry.map_or(true, |distance| candidate < distance) {
+                        *entry = Some(candidate);
+                    }
+                }
+            }
+        }
+    }
+
+    for (node_index, row) in distances.iter_mut().enumerate() {
+        if row[node_index].is_some_and(|distance| distance < 0) {
+            row[node_index] = None;
+        }
+    }
+    distances
+}
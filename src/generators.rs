@@ -0,0 +1,93 @@
+//! Generators for graphs with a known structure, e.g. for testing and benchmarking.
+
+use crate::{
+    graph::{Edge, MutableGraph},
+    simplegraph::SimpleGraph,
+    util::XorShift64Star,
+    NodeId,
+};
+use alloc::vec::Vec;
+
+/// Returns a random directed graph with `n` nodes, in which each possible edge exists with probability `p`.
+///
+/// Self-loops are not considered possible edges.
+/// The graph is generated from a seeded pseudo random number generator, such that the same seed always yields the same graph.
+pub fn gnp_random_graph(n: usize, p: f64, seed: u64) -> SimpleGraph<(), ()> {
+    let mut random = XorShift64Star::new(seed);
+    let (mut graph, nodes) = empty_graph(n);
+    for &start in &nodes {
+        for &end in &nodes {
+            if start != end && random.next_f64() < p {
+                add_edge(&mut graph, start, end);
+            }
+        }
+    }
+    graph
+}
+
+/// Returns a directed path with `n` nodes, with an edge from each node to the node with the next higher id.
+pub fn path_graph(n: usize) -> SimpleGraph<(), ()> {
+    let (mut graph, nodes) = empty_graph(n);
+    for window in nodes.windows(2) {
+        add_edge(&mut graph, window[0], window[1]);
+    }
+    graph
+}
+
+/// Returns a directed cycle with `n` nodes.
+/// This is a path graph with an additional edge from the last node to the first node.
+pub fn cycle_graph(n: usize) -> SimpleGraph<(), ()> {
+    let mut graph = path_graph(n);
+    if n > 0 {
+        add_edge(&mut graph, NodeId::from(n - 1), NodeId::from(0));
+    }
+    graph
+}
+
+/// Returns a complete directed graph with `n` nodes, i.e. with an edge between each ordered pair of distinct nodes.
+pub fn complete_graph(n: usize) -> SimpleGraph<(), ()> {
+    let (mut graph, nodes) = empty_graph(n);
+    for &start in &nodes {
+        for &end in &nodes {
+            if start != end {
+                add_edge(&mut graph, start, end);
+            }
+        }
+    }
+    graph
+}
+
+/// Returns a grid with `width` columns and `height` rows, in which each cell is connected to its orthogonal neighbors in both directions.
+///
+/// The cell in row `row` and column `col` has the node id `row * width + col`.
+pub fn grid_graph(width: usize, height: usize) -> SimpleGraph<(), ()> {
+    let (mut graph, nodes) = empty_graph(width * height);
+    for row in 0..height {
+        for col in 0..width {
+            let node = nodes[row * width + col];
+            if col + 1 < width {
+                let right = nodes[row * width + col + 1];
+                add_edge(&mut graph, node, right);
+                add_edge(&mut graph, right, node);
+            }
+            if row + 1 < height {
+                let below = nodes[(row + 1) * width + col];
+                add_edge(&mut graph, node, below);
+                add_edge(&mut graph, below, node);
+            }
+        }
+    }
+    graph
+}
+
+fn empty_graph(n: usize) -> (SimpleGraph<(), ()>, Vec<NodeId>) {
+    let mut graph = SimpleGraph::new();
+    let nodes = (0..n).map(|_| graph.add_anonymous_node()).collect();
+    (graph, nodes)
+}
+
+fn add_edge(graph: &mut SimpleGraph<(), ()>, start: NodeId, end: NodeId) {
+    graph
+        .add_edge(Edge::new(start, end, ()))
+        .unwrap_or_else(|error| panic!("Generated edge is invalid: {:?}", error));
+}
@@ -1,6 +1,7 @@
 //! Defines different traits of graphs.
 
 use crate::{EdgeId, IdType, NodeId};
+use alloc::boxed::Box;
 
 /// A basic graph.
 ///
@@ -8,9 +9,9 @@ use crate::{EdgeId, IdType, NodeId};
 /// Their functionality is very limited though, as not even navigation is defined.
 pub trait Graph<N, E> {
     /// An iterator over all node ids of a graph.
-    type NodeIdIterator: Iterator<Item = NodeId>;
+    type NodeIdIterator: Iterator<Item = NodeId> + DoubleEndedIterator + ExactSizeIterator;
     /// An iterator over all edge ids of a graph.
-    type EdgeIdIterator: Iterator<Item = EdgeId>;
+    type EdgeIdIterator: Iterator<Item = EdgeId> + DoubleEndedIterator + ExactSizeIterator;
 
     /// The amount of nodes in the graph.
     fn node_len(&self) -> IdType;
@@ -31,7 +32,7 @@ pub trait Graph<N, E> {
     fn edge_data(&self, id: EdgeId) -> &E;
 
     /// Returns an edge instance, identified by the given id.
-    fn edge(&self, id: EdgeId) -> EdgeRef<E>;
+    fn edge(&self, id: EdgeId) -> EdgeRef<'_, E>;
 
     /// Returns the start node of the edge identified by the given id.
     fn edge_start(&self, id: EdgeId) -> NodeId;
@@ -39,11 +40,92 @@ pub trait Graph<N, E> {
     /// Returns the end node of the edge identified by the given id.
     fn edge_end(&self, id: EdgeId) -> NodeId;
 
+    /// Returns the start and end node of the edge identified by the given id.
+    fn edge_endpoints(&self, id: EdgeId) -> (NodeId, NodeId) {
+        (self.edge_start(id), self.edge_end(id))
+    }
+
+    /// Returns an iterator over all edges in the graph, as tuples of edge id, start node, end node and edge data.
+    /// The edges are returned in edge id order.
+    fn full_edge_iter<'b>(&'b self) -> impl Iterator<Item = (EdgeId, NodeId, NodeId, &'b E)>
+    where
+        E: 'b,
+    {
+        self.edge_id_iter().map(move |id| {
+            (
+                id,
+                self.edge_start(id),
+                self.edge_end(id),
+                self.edge_data(id),
+            )
+        })
+    }
+
+    /// Returns an iterator over the ids of all self-loops in the graph, i.e. edges whose start node equals their end node.
+    /// The edges are returned in edge id order.
+    ///
+    /// This scans all edges with `full_edge_iter`, so it benefits from representations that override that method.
+    fn self_loop_edges<'b>(&'b self) -> impl Iterator<Item = EdgeId>
+    where
+        E: 'b,
+    {
+        self.full_edge_iter()
+            .filter(|&(_, start, end, _)| start == end)
+            .map(|(id, _, _, _)| id)
+    }
+
+    /// Returns true if the edges of this graph are directed.
+    ///
+    /// Undirected graph representations override this to return false,
+    /// such that generic algorithms can treat their edges symmetrically.
+    fn is_directed(&self) -> bool {
+        true
+    }
+
     /// Returns true if the given `NodeId` refers to a node in this graph.
+    ///
+    /// This is the canonical check for node ids, and is used by the accessors of this trait.
+    /// Unlike `NodeId::is_valid`, it takes the amount of nodes of this specific graph into account,
+    /// so it rejects ids that were created by a larger graph.
     fn is_node_id_valid(&self, id: NodeId) -> bool;
 
+    /// Returns true if the given `NodeId` is smaller than the amount of nodes of this graph.
+    ///
+    /// This check is specific to this graph: an id that is in bounds for one graph may be out of bounds for another.
+    /// For the graphs of this crate, it is equivalent to `is_node_id_valid`.
+    fn node_id_in_bounds(&self, id: NodeId) -> bool {
+        id.is_valid() && <usize>::from(id) < self.node_len() as usize
+    }
+
     /// Returns true if the given `EdgeId` refers to an edge in this graph.
     fn is_edge_id_valid(&self, id: EdgeId) -> bool;
+
+    /// Returns a reference to a nodes data, identified by the given id, or `None` if the id is invalid.
+    fn get_node_data(&self, id: NodeId) -> Option<&N> {
+        if self.is_node_id_valid(id) {
+            Some(self.node_data(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an edge instance, identified by the given id, or `None` if the id is invalid.
+    fn get_edge(&self, id: EdgeId) -> Option<EdgeRef<'_, E>> {
+        if self.is_edge_id_valid(id) {
+            Some(self.edge(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to an edges data, identified by the given id, or `None` if the id is invalid.
+    fn get_edge_data(&self, id: EdgeId) -> Option<&E> {
+        if self.is_edge_id_valid(id) {
+            Some(self.edge_data(id))
+        } else {
+            None
+        }
+    }
 }
 
 // TODO implement as soon as https://github.com/rust-lang/rust/issues/29661 is implemented
@@ -53,9 +135,9 @@ pub trait Graph<N, E> {
 /// If that is the case, the provided default methods in this trait should be overwritten with the more efficient variant.
 pub trait IterableGraph<'a, N: 'a, E: 'a>: Graph<N, E> {
     /// An iterator over all nodes of a graph.
-    type NodeIterator: Iterator<Item = (NodeId, NodeRef<'a, N>)> = std::iter::Map<<Self as Graph<N, E>>::NodeIdIterator, fn(NodeId) -> (NodeId, NodeRef<'a, N>)>;
+    type NodeIterator: Iterator<Item = (NodeId, NodeRef<'a, N>)> = core::iter::Map<<Self as Graph<N, E>>::NodeIdIterator, fn(NodeId) -> (NodeId, NodeRef<'a, N>)>;
     /// An iterator over all edges of a graph.
-    type EdgeIterator: Iterator<Item = (EdgeId, EdgeRef<'a, E>)> = std::iter::Map<<Self as Graph<N, E>>::EdgeIdIterator, fn(EdgeId) -> (EdgeId, EdgeRef<'a, E>)>;
+    type EdgeIterator: Iterator<Item = (EdgeId, EdgeRef<'a, E>)> = core::iter::Map<<Self as Graph<N, E>>::EdgeIdIterator, fn(EdgeId) -> (EdgeId, EdgeRef<'a, E>)>;
 
     /// Returns an iterator over all nodes in the graph.
     fn node_iter(&self) -> Self::NodeIterator {
@@ -72,12 +154,34 @@ pub trait IterableGraph<'a, N: 'a, E: 'a>: Graph<N, E> {
 ///
 /// Graphs implementing this trait are able to output a nodes out-edges efficiently.
 /// For undirected graphs, out-edges and in-edges are the same.
+///
+/// The out-edges of a node are returned in the order in which the edges were added to the graph,
+/// or, for converted graphs, in the order in which they were added to the source graph.
 pub trait ForwardNavigableGraph<'a, N, E>: Graph<N, E> {
     /// An iterator over the out-edges of a node.
     type OutEdgeIterator: Iterator<Item = EdgeId> + 'a;
 
     /// Returns an iterator over the out-edges of the node identified by the given id.
+    /// The edges are returned in insertion order.
     fn out_edges(&self, id: NodeId) -> Self::OutEdgeIterator;
+
+    /// Returns an iterator over the out-edges of the node identified by the given id as edge instances.
+    /// The edges are returned in the same order as by `out_edges`.
+    fn out_edge_refs(&'a self, id: NodeId) -> impl Iterator<Item = EdgeRef<'a, E>> + 'a
+    where
+        E: 'a,
+    {
+        self.out_edges(id).map(move |edge_id| self.edge(edge_id))
+    }
+
+    /// Returns an iterator over all edges from the node `start` to the node `end`.
+    /// The edges are returned in the same order as by `out_edges`.
+    ///
+    /// Since out-edges are kept in insertion order rather than sorted by end node, this scans all out-edges of `start`.
+    fn edges_between(&'a self, start: NodeId, end: NodeId) -> impl Iterator<Item = EdgeId> + 'a {
+        self.out_edges(start)
+            .filter(move |&edge_id| self.edge_end(edge_id) == end)
+    }
 }
 
 /// A backward navigable graph.
@@ -108,16 +212,188 @@ pub trait MutableGraph<N, E> {
     fn add_edge(&mut self, edge: Edge<E>) -> Result<EdgeId, GraphModificationError>;
 }
 
+/// An object-safe facade for `Graph`.
+///
+/// `Graph` has associated iterator types and methods returning `impl Iterator`, so it cannot be used as a trait object.
+/// This trait provides the basic functionality of `Graph` with boxed iterators instead,
+/// such that different graph representations can be stored as `Box<dyn DynGraph<N, E>>`.
+/// It is implemented for all graphs whose id iterators do not borrow from the graph, which includes all graphs of this crate.
+///
+/// The methods are prefixed with `dyn_`, such that calls stay unambiguous when both traits are in scope.
+pub trait DynGraph<N, E> {
+    /// The amount of nodes in the graph.
+    fn dyn_node_len(&self) -> IdType;
+
+    /// The amount of edges in the graph.
+    fn dyn_edge_len(&self) -> IdType;
+
+    /// Returns an iterator over all node ids in the graph.
+    fn dyn_node_ids(&self) -> Box<dyn Iterator<Item = NodeId> + '_>;
+
+    /// Returns an iterator over all edge ids in the graph.
+    fn dyn_edge_ids(&self) -> Box<dyn Iterator<Item = EdgeId> + '_>;
+
+    /// Returns a reference to a nodes data, identified by the given id.
+    fn dyn_node_data(&self, id: NodeId) -> &N;
+
+    /// Returns a reference to an edges data, identified by the given id.
+    fn dyn_edge_data(&self, id: EdgeId) -> &E;
+
+    /// Returns an edge instance, identified by the given id.
+    fn dyn_edge(&self, id: EdgeId) -> EdgeRef<'_, E>;
+
+    /// Returns true if the given `NodeId` refers to a node in this graph.
+    fn dyn_is_node_id_valid(&self, id: NodeId) -> bool;
+
+    /// Returns true if the given `EdgeId` refers to an edge in this graph.
+    fn dyn_is_edge_id_valid(&self, id: EdgeId) -> bool;
+}
+
+impl<N, E, G: Graph<N, E>> DynGraph<N, E> for G
+where
+    G::NodeIdIterator: 'static,
+    G::EdgeIdIterator: 'static,
+{
+    fn dyn_node_len(&self) -> IdType {
+        Graph::node_len(self)
+    }
+
+    fn dyn_edge_len(&self) -> IdType {
+        Graph::edge_len(self)
+    }
+
+    fn dyn_node_ids(&self) -> Box<dyn Iterator<Item = NodeId> + '_> {
+        Box::new(self.node_id_iter())
+    }
+
+    fn dyn_edge_ids(&self) -> Box<dyn Iterator<Item = EdgeId> + '_> {
+        Box::new(self.edge_id_iter())
+    }
+
+    fn dyn_node_data(&self, id: NodeId) -> &N {
+        Graph::node_data(self, id)
+    }
+
+    fn dyn_edge_data(&self, id: EdgeId) -> &E {
+        Graph::edge_data(self, id)
+    }
+
+    fn dyn_edge(&self, id: EdgeId) -> EdgeRef<'_, E> {
+        Graph::edge(self, id)
+    }
+
+    fn dyn_is_node_id_valid(&self, id: NodeId) -> bool {
+        Graph::is_node_id_valid(self, id)
+    }
+
+    fn dyn_is_edge_id_valid(&self, id: EdgeId) -> bool {
+        Graph::is_edge_id_valid(self, id)
+    }
+}
+
+/// Converts the given graph into any mutable graph representation.
+///
+/// All nodes are added to the target in node id order, followed by all edges in edge id order.
+/// If the target assigns ids sequentially, like `SimpleGraph`, node and edge ids are preserved.
+pub fn convert<N: Clone, E: Clone, G: Graph<N, E>, H: MutableGraph<N, E>>(source: &G) -> H {
+    let mut target = H::new();
+    for id in source.node_id_iter() {
+        target.add_node(Node::new(source.node_data(id).clone()));
+    }
+    for id in source.edge_id_iter() {
+        target
+            .add_edge(source.edge(id).into())
+            .unwrap_or_else(|error| panic!("Source graph contains an invalid edge: {:?}", error));
+    }
+    target
+}
+
+/// Asserts that the two given graphs contain the same edges under the same edge ids.
+///
+/// This is meant for tests of conversions between graph representations.
+/// Two edges are considered the same if they have the same start node, end node and data.
+///
+/// # Panics
+///
+/// Panics if the graphs have a different amount of edges, or if any edge id refers to different edges in the two graphs.
+pub fn assert_edge_ids_preserved<N, E, G1, G2>(a: &G1, b: &G2)
+where
+    E: PartialEq + core::fmt::Debug,
+    G1: Graph<N, E>,
+    G2: Graph<N, E>,
+{
+    assert_eq!(a.edge_len(), b.edge_len(), "Edge len differs");
+    for id in a.edge_id_iter() {
+        assert_eq!(a.edge(id), b.edge(id), "Edge {:?} differs", id);
+    }
+}
+
+/// Returns true if the two given graphs have the same structure under the identity mapping of ids.
+///
+/// This is the case if both graphs have the same amount of nodes and edges,
+/// and each edge id refers to edges with the same start and end node in both graphs.
+/// Node and edge data are not compared. This is meant for tests of conversions, see `assert_edge_ids_preserved`
+/// for the conditions under which conversions preserve edge ids.
+pub fn same_structure<N, E, G1: Graph<N, E>, G2: Graph<N, E>>(a: &G1, b: &G2) -> bool {
+    a.node_len() == b.node_len()
+        && a.edge_len() == b.edge_len()
+        && a.edge_id_iter()
+            .all(|id| a.edge_endpoints(id) == b.edge_endpoints(id))
+}
+
+/// Edge data that can be interpreted as a numeric weight.
+///
+/// Weighted algorithms like `dijkstra` use this trait to compute the cost of an edge from its data.
+pub trait EdgeWeight {
+    /// Returns the weight of this edge.
+    fn weight(&self) -> f64;
+}
+
+macro_rules! impl_edge_weight {
+    ($($weight_type:ty),*) => {
+        $(
+            impl EdgeWeight for $weight_type {
+                fn weight(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_edge_weight!(f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
 /// An error type for graph modifications.
-/// This type is used by the `MutableGraph` trait.
+/// This type is used by the `MutableGraph` trait and `AdjacencyArray::from_edges`.
 #[derive(Debug)]
 pub enum GraphModificationError {
     /// An edge that refers to a nonexistent start node was added to the graph
     StartNodeDoesNotExist,
     /// An edge that refers to a nonexistent end node was added to the graph
     EndNodeDoesNotExist,
+    /// The amount of node data does not match the amount of nodes of the graph
+    NodeDataLenMismatch,
+}
+
+impl core::fmt::Display for GraphModificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        match self {
+            GraphModificationError::StartNodeDoesNotExist => {
+                write!(f, "The start node of the edge does not exist")
+            },
+            GraphModificationError::EndNodeDoesNotExist => {
+                write!(f, "The end node of the edge does not exist")
+            },
+            GraphModificationError::NodeDataLenMismatch => {
+                write!(f, "The node data len does not match the node count")
+            },
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for GraphModificationError {}
+
 /// A container for a node.
 /// Can be used to add nodes to a `MutableGraph`.
 #[derive(Debug)]
@@ -168,6 +444,15 @@ impl<E> Edge<E> {
         Self { start, end, data }
     }
 
+    /// Creates a new edge between the nodes with the given raw indices, with the given edge data.
+    ///
+    /// This is useful when loading graphs from external sources that identify nodes by integers.
+    /// The indices are not checked here, but only once the edge is added to a graph with `MutableGraph::add_edge`.
+    /// Panics if an index does not fit into the id type.
+    pub fn from_indices(start: usize, end: usize, data: E) -> Self {
+        Self::new(NodeId::from(start), NodeId::from(end), data)
+    }
+
     /// Returns the id of the start node of this edge.
     pub fn start(&self) -> NodeId {
         self.start
@@ -182,6 +467,16 @@ impl<E> Edge<E> {
     pub fn data(&self) -> &E {
         &self.data
     }
+
+    /// Consumes this edge and returns its data.
+    pub fn into_data(self) -> E {
+        self.data
+    }
+
+    /// Swaps the start and end node of this edge.
+    pub(crate) fn reverse(&mut self) {
+        core::mem::swap(&mut self.start, &mut self.end);
+    }
 }
 
 impl<'a, N> NodeRef<'a, N> {
@@ -222,6 +517,17 @@ impl<'a, E> EdgeRef<'a, E> {
     }
 }
 
+impl<'a, E: PartialEq> EdgeRef<'a, E> {
+    /// Returns true if this edge equals the given edge when ignoring their direction.
+    ///
+    /// This is the case if both edges have equal data and connect the same pair of nodes, in any order.
+    pub fn eq_undirected(&self, other: &EdgeRef<'_, E>) -> bool {
+        self.data == other.data
+            && ((self.start == other.start && self.end == other.end)
+                || (self.start == other.end && self.end == other.start))
+    }
+}
+
 impl<'a, E> From<&'a Edge<E>> for EdgeRef<'a, E> {
     fn from(edge: &'a Edge<E>) -> Self {
         EdgeRef::new(edge.start(), edge.end(), edge.data())
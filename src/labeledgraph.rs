@@ -0,0 +1,54 @@
+//! A wrapper that attaches graph-level metadata to a graph.
+//!
+//! The `LabeledGraph` dereferences to the wrapped graph, so it can be used like the graph itself,
+//! while the metadata, e.g. a name or a timestamp, is carried alongside it.
+
+use core::ops::{Deref, DerefMut};
+
+/// A graph together with graph-level metadata.
+#[derive(Debug, Clone)]
+pub struct LabeledGraph<G, M> {
+    graph: G,
+    meta: M,
+}
+
+impl<G, M> LabeledGraph<G, M> {
+    /// Wraps the given graph together with the given metadata.
+    pub fn new(graph: G, meta: M) -> Self {
+        Self { graph, meta }
+    }
+
+    /// Returns a reference to the metadata.
+    pub fn meta(&self) -> &M {
+        &self.meta
+    }
+
+    /// Returns a mutable reference to the metadata.
+    pub fn meta_mut(&mut self) -> &mut M {
+        &mut self.meta
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Consumes this wrapper and returns the graph and the metadata.
+    pub fn into_parts(self) -> (G, M) {
+        (self.graph, self.meta)
+    }
+}
+
+impl<G, M> Deref for LabeledGraph<G, M> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.graph
+    }
+}
+
+impl<G, M> DerefMut for LabeledGraph<G, M> {
+    fn deref_mut(&mut self) -> &mut G {
+        &mut self.graph
+    }
+}
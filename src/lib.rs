@@ -8,13 +8,21 @@
 //! If you are missing a feature or found a bug, please open an issue on [github](https://github.com/ISibboI/graphrepresentations/issues).
 
 #![deny(missing_docs)]
+#![no_std]
 
-use std::convert::TryInto;
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::convert::TryInto;
 
 pub mod adjacencyarray;
+pub mod algorithms;
+pub mod generators;
 pub mod graph;
+pub mod labeledgraph;
 pub mod simplegraph;
-mod util;
+pub mod util;
 
 ///////////////////////////////
 ///// IDENTIFIERS /////////////
@@ -26,7 +34,7 @@ pub type IdType = u32;
 /// Identifies a node in a graph.
 ///
 /// This struct cannot be instantiated or modified by the client.
-#[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub struct NodeId {
     id: IdType,
 }
@@ -34,7 +42,7 @@ pub struct NodeId {
 /// Identifies an edge in a graph.
 ///
 /// This struct cannot be instantiated or modified by the client.
-#[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub struct EdgeId {
     id: IdType,
 }
@@ -48,15 +56,17 @@ impl NodeId {
 
     fn invalid() -> Self {
         NodeId {
-            id: IdType::max_value(),
+            id: IdType::MAX,
         }
     }
 
     /// Checks if this `NodeId` is valid.
     /// Does not account for id changes due to graph modifications.
+    ///
+    /// This only rejects the internal invalid id, and does not check if the id is in bounds of a specific graph.
+    /// Use `Graph::is_node_id_valid` to check if an id refers to a node of a graph.
     pub fn is_valid(&self) -> bool {
-        #![allow(unused_comparisons)]
-        self.id >= 0 && *self != Self::invalid()
+        *self != Self::invalid()
     }
 }
 
@@ -69,26 +79,25 @@ impl EdgeId {
 
     fn invalid() -> Self {
         EdgeId {
-            id: IdType::max_value(),
+            id: IdType::MAX,
         }
     }
 
     /// Checks if this `EdgeId` is valid.
     /// Does not account for id changes due to graph modifications.
     pub fn is_valid(&self) -> bool {
-        #![allow(unused_comparisons)]
-        self.id >= 0 && *self != Self::invalid()
+        *self != Self::invalid()
     }
 }
 
-impl std::fmt::Debug for NodeId {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Debug for NodeId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         write!(f, "N{}", self.id)
     }
 }
 
-impl std::fmt::Debug for EdgeId {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Debug for EdgeId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         write!(f, "E{}", self.id)
     }
 }
@@ -33,16 +33,17 @@ use crate::{
     adjacencyarray::AdjacencyArray,
     graph::{Edge, EdgeRef, Graph, GraphModificationError, MutableGraph, Node},
     simplegraph::iterators::{SimpleGraphEdgeIdIterator, SimpleGraphNodeIdIterator},
+    util::vec_bytes,
     EdgeId, IdType, NodeId,
 };
-use std::{borrow::Borrow, convert::TryInto};
+use alloc::{vec, vec::Vec};
+use core::{borrow::Borrow, convert::TryInto};
 
 pub mod iterators;
 
 /// A simple graph representation that is inefficient to use, but cheap to construct.
 ///
 /// For actual usage, the graph should be converted into a different representation.
-#[derive(Debug)]
 pub struct SimpleGraph<N, E> {
     nodes: Vec<Node<N>>,
     edges: Vec<Edge<E>>,
@@ -61,11 +62,11 @@ impl<N, E> Graph<N, E> for SimpleGraph<N, E> {
     }
 
     fn node_id_iter(&self) -> Self::NodeIdIterator {
-        (0..self.node_len()).map(|id| NodeId::new(id))
+        (0..self.node_len()).map(NodeId::new)
     }
 
     fn edge_id_iter(&self) -> Self::EdgeIdIterator {
-        (0..self.edge_len()).map(|id| EdgeId::new(id))
+        (0..self.edge_len()).map(EdgeId::new)
     }
 
     fn node_data(&self, id: NodeId) -> &N {
@@ -78,7 +79,7 @@ impl<N, E> Graph<N, E> for SimpleGraph<N, E> {
         self.edges[<EdgeId as Into<usize>>::into(id)].data()
     }
 
-    fn edge(&self, id: EdgeId) -> EdgeRef<E> {
+    fn edge(&self, id: EdgeId) -> EdgeRef<'_, E> {
         assert!(self.is_edge_id_valid(id));
         self.edges[<EdgeId as Into<usize>>::into(id)]
             .borrow()
@@ -134,6 +135,178 @@ impl<N, E> MutableGraph<N, E> for SimpleGraph<N, E> {
     }
 }
 
+impl<N, E> SimpleGraph<N, E> {
+    /// Creates a new empty graph with space reserved for the given amount of nodes and edges.
+    pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
+        SimpleGraph {
+            nodes: Vec::with_capacity(node_capacity),
+            edges: Vec::with_capacity(edge_capacity),
+        }
+    }
+
+    /// Releases reserved but unused memory.
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+
+    /// Reverses the direction of the edge identified by the given id by swapping its start and end node.
+    /// The id and data of the edge stay the same.
+    pub fn reverse_edge(&mut self, id: EdgeId) {
+        assert!(self.is_edge_id_valid(id));
+        self.edges[<EdgeId as Into<usize>>::into(id)].reverse();
+    }
+
+    /// Removes all edges for which `keep` returns false.
+    ///
+    /// The remaining edges keep their relative order, but their ids are renumbered to be consecutive again.
+    /// Node ids are not changed.
+    pub fn retain_edges(&mut self, mut keep: impl FnMut(EdgeId, &Edge<E>) -> bool) {
+        let mut edge_index = 0;
+        self.edges.retain(|edge| {
+            let id = EdgeId::from(edge_index);
+            edge_index += 1;
+            keep(id, edge)
+        });
+    }
+
+    /// Removes all nodes for which `keep` returns false, together with all their incident edges.
+    ///
+    /// The remaining nodes and edges keep their relative order, but their ids are renumbered to be consecutive again.
+    /// The return value maps the old node ids to their new ids, indexed by the old node id, with `None` for removed nodes.
+    /// This is the mutable counterpart of `algorithms::filter_nodes`.
+    pub fn retain_nodes(
+        &mut self,
+        mut keep: impl FnMut(NodeId, &Node<N>) -> bool,
+    ) -> Vec<Option<NodeId>> {
+        let mut node_len = 0;
+        let node_map: Vec<_> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(node_index, node)| {
+                if keep(NodeId::from(node_index), node) {
+                    node_len += 1;
+                    Some(NodeId::from(node_len - 1))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut node_index = 0;
+        self.nodes.retain(|_| {
+            node_index += 1;
+            node_map[node_index - 1].is_some()
+        });
+        self.edges = self
+            .edges
+            .drain(..)
+            .filter_map(|edge| {
+                let start = node_map[<usize>::from(edge.start())]?;
+                let end = node_map[<usize>::from(edge.end())]?;
+                Some(Edge::new(start, end, edge.into_data()))
+            })
+            .collect();
+
+        node_map
+    }
+
+    /// Reorders the edges of this graph by their start node, keeping the relative order of edges with the same start node.
+    ///
+    /// This changes edge ids, but not node ids.
+    /// Afterwards, converting this graph into an `AdjacencyArray` preserves edge ids.
+    pub fn sort_edges_by_start(&mut self) {
+        self.edges.sort_by_key(|edge| edge.start());
+    }
+
+    /// Returns the amount of heap memory used by this graph in bytes.
+    ///
+    /// This is computed from the capacities of the internal vectors.
+    /// Heap memory owned by the node and edge data themselves is not included.
+    pub fn memory_bytes(&self) -> usize {
+        vec_bytes(&self.nodes) + vec_bytes(&self.edges)
+    }
+}
+
+impl<N, E: Ord> SimpleGraph<N, E> {
+    /// Removes all edges that equal an edge with a smaller id in start node, end node and data.
+    ///
+    /// The remaining edges keep their relative order, but their ids are renumbered to be consecutive again.
+    /// Node ids are not changed.
+    /// Duplicates are found by sorting the edges, which takes O(m log m) time for m edges.
+    pub fn dedup_edges(&mut self) {
+        let key = |index: usize| {
+            let edge = &self.edges[index];
+            (edge.start(), edge.end(), edge.data())
+        };
+        // A stable sort makes duplicates adjacent, with the edge of the smallest id first.
+        let mut order: Vec<_> = (0..self.edges.len()).collect();
+        order.sort_by(|&a, &b| key(a).cmp(&key(b)));
+
+        let mut keep = vec![true; self.edges.len()];
+        for window in order.windows(2) {
+            keep[window[1]] = key(window[0]) != key(window[1]);
+        }
+
+        let mut edge_index = 0;
+        self.edges.retain(|_| {
+            edge_index += 1;
+            keep[edge_index - 1]
+        });
+    }
+}
+
+impl<N: Default, E> SimpleGraph<N, E> {
+    /// Adds a node with default node data to the graph.
+    /// The return value is the id assigned to the new node.
+    pub fn add_anonymous_node(&mut self) -> NodeId {
+        self.add_node(Node::new(N::default()))
+    }
+}
+
+impl SimpleGraph<(), ()> {
+    /// Creates a graph without node or edge data from the given adjacency lists.
+    ///
+    /// The outer vector is indexed by node, and the inner vectors contain the indices of the end nodes of the out-edges of the respective node.
+    /// The graph has one node per adjacency list, and its edges are ordered by start node, so converting it into an `AdjacencyArray` preserves edge ids.
+    /// Returns an error if any end node index is out of bounds.
+    pub fn from_adjacency_lists(lists: Vec<Vec<usize>>) -> Result<Self, GraphModificationError> {
+        let mut result = Self::with_capacity(lists.len(), lists.iter().map(Vec::len).sum());
+        for _ in 0..lists.len() {
+            result.add_node(Node::new(()));
+        }
+        for (start, ends) in lists.into_iter().enumerate() {
+            for end in ends {
+                if end >= result.nodes.len() {
+                    return Err(GraphModificationError::EndNodeDoesNotExist);
+                }
+                result.add_edge(Edge::from_indices(start, end, ()))?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Graphs with more nodes plus edges than this are only summarized by their `Debug` implementation.
+const DEBUG_ELEMENT_LIMIT: usize = 16;
+
+impl<N: core::fmt::Debug, E: core::fmt::Debug> core::fmt::Debug for SimpleGraph<N, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        if self.nodes.len() + self.edges.len() <= DEBUG_ELEMENT_LIMIT {
+            f.debug_struct("SimpleGraph")
+                .field("nodes", &self.nodes)
+                .field("edges", &self.edges)
+                .finish()
+        } else {
+            f.debug_struct("SimpleGraph")
+                .field("node_len", &self.nodes.len())
+                .field("edge_len", &self.edges.len())
+                .finish()
+        }
+    }
+}
+
 impl<N, E> Default for SimpleGraph<N, E> {
     fn default() -> Self {
         SimpleGraph {
@@ -143,6 +316,34 @@ impl<N, E> Default for SimpleGraph<N, E> {
     }
 }
 
+/// Returns the disjoint union of the two given graphs.
+///
+/// The nodes and edges of `a` keep their ids, and the nodes and edges of `b` are added after them.
+/// The second return value maps the node ids of `b` to their new ids, indexed by the old node id.
+pub fn disjoint_union<N: Clone, E: Clone>(
+    a: &SimpleGraph<N, E>,
+    b: &SimpleGraph<N, E>,
+) -> (SimpleGraph<N, E>, Vec<NodeId>) {
+    let mut result = convert_from(a);
+    result.nodes.reserve(b.nodes.len());
+    result.edges.reserve(b.edges.len());
+
+    let node_map: Vec<_> = b
+        .nodes
+        .iter()
+        .map(|node| result.add_node(Node::new(node.data().clone())))
+        .collect();
+    for edge in &b.edges {
+        let start = node_map[<usize>::from(edge.start())];
+        let end = node_map[<usize>::from(edge.end())];
+        result
+            .add_edge(Edge::new(start, end, edge.data().clone()))
+            .unwrap_or_else(|error| panic!("Remapped edge is invalid: {:?}", error));
+    }
+
+    (result, node_map)
+}
+
 fn convert_from<N: Clone, E: Clone, G: Graph<N, E>>(source: &G) -> SimpleGraph<N, E> {
     let nodes: Vec<_> = source
         .node_id_iter()
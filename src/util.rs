@@ -1,8 +1,21 @@
-use crate::{EdgeId, NodeId};
-use std::ops::AddAssign;
+//! Utility types that are shared between graph representations and algorithms.
 
+use crate::{EdgeId, IdType, NodeId};
+use alloc::{vec, vec::Vec};
+use core::{convert::TryInto, ops::AddAssign};
+
+/// Prefix sums over a sequence of values.
+///
+/// All implementations compute inclusive prefix sums in place, and exclusive prefix sums when cloning.
 pub trait PrefixSum<T> {
+    /// Replaces each value with the sum of itself and all values before it, i.e. computes the inclusive prefix sum in place.
+    /// Returns the total sum.
     fn prefix_sum(&mut self) -> T;
+
+    /// Returns the exclusive prefix sum without modifying the values, together with the total sum.
+    ///
+    /// The entry at each index is the sum of all values before that index, so the first entry is zero.
+    fn prefix_sum_cloned(&self) -> (Vec<T>, T);
 }
 
 impl<T: AddAssign + Default + Clone> PrefixSum<T> for Vec<T> {
@@ -15,20 +28,24 @@ impl<T: AddAssign + Default + Clone> PrefixSum<T> for Vec<T> {
         }
         accumulator
     }
-}
 
-impl PrefixSum<NodeId> for Vec<NodeId> {
-    fn prefix_sum(&mut self) -> NodeId {
-        let mut accumulator = 0;
-        for item in self.iter_mut() {
-            let value = item.id;
-            item.id += accumulator;
-            accumulator += value;
-        }
-        NodeId::new(accumulator)
+    fn prefix_sum_cloned(&self) -> (Vec<T>, T) {
+        let mut accumulator = T::default();
+        let result = self
+            .iter()
+            .map(|item| {
+                let sum = accumulator.clone();
+                accumulator += item.clone();
+                sum
+            })
+            .collect();
+        (result, accumulator)
     }
 }
 
+/// `EdgeId` does not implement `AddAssign`, so edge ids get a dedicated implementation.
+/// It computes the same inclusive prefix sum as the generic implementation, applied to the raw ids.
+/// This is used to compute the `first_out` array of the `AdjacencyArray` and the `first_in` array of the `BidirectionalAdjacencyArray`.
 impl PrefixSum<EdgeId> for Vec<EdgeId> {
     fn prefix_sum(&mut self) -> EdgeId {
         let mut accumulator = 0;
@@ -39,4 +56,117 @@ impl PrefixSum<EdgeId> for Vec<EdgeId> {
         }
         EdgeId::new(accumulator)
     }
+
+    fn prefix_sum_cloned(&self) -> (Vec<EdgeId>, EdgeId) {
+        let mut accumulator = 0;
+        let result = self
+            .iter()
+            .map(|item| {
+                let sum = accumulator;
+                accumulator += item.id;
+                EdgeId::new(sum)
+            })
+            .collect();
+        (result, EdgeId::new(accumulator))
+    }
+}
+
+/// Returns the amount of heap memory allocated by the given vector in bytes.
+pub(crate) fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * core::mem::size_of::<T>()
+}
+
+/// A set of nodes, stored as a bitset with one bit per node.
+///
+/// This is more compact than a `Vec<bool>` and is used by traversal algorithms to mark visited nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeBitset {
+    words: Vec<u64>,
+    node_len: IdType,
+}
+
+impl NodeBitset {
+    const WORD_BITS: usize = 64;
+
+    /// Creates a new empty bitset for a graph with the given amount of nodes.
+    pub fn new(node_len: IdType) -> Self {
+        let bit_len: usize = node_len
+            .try_into()
+            .expect("Node len incompatible with usize");
+        let word_len = bit_len.div_ceil(Self::WORD_BITS);
+        Self {
+            words: vec![0; word_len],
+            node_len,
+        }
+    }
+
+    /// The amount of nodes this bitset can hold.
+    pub fn node_len(&self) -> IdType {
+        self.node_len
+    }
+
+    /// Adds the node identified by the given id to the set.
+    pub fn set(&mut self, id: NodeId) {
+        let (word, mask) = self.locate(id);
+        self.words[word] |= mask;
+    }
+
+    /// Returns true if the node identified by the given id is in the set.
+    pub fn contains(&self, id: NodeId) -> bool {
+        let (word, mask) = self.locate(id);
+        self.words[word] & mask != 0
+    }
+
+    /// Removes the node identified by the given id from the set.
+    pub fn clear(&mut self, id: NodeId) {
+        let (word, mask) = self.locate(id);
+        self.words[word] &= !mask;
+    }
+
+    fn locate(&self, id: NodeId) -> (usize, u64) {
+        assert!(
+            id.is_valid() && id.id < self.node_len,
+            "Node id out of bounds: {:?}",
+            id
+        );
+        let index: usize = id.into();
+        (index / Self::WORD_BITS, 1 << (index % Self::WORD_BITS))
+    }
+}
+
+/// A small xorshift* pseudo random number generator.
+pub(crate) struct XorShift64Star {
+    state: u64,
+}
+
+impl XorShift64Star {
+    pub(crate) fn new(seed: u64) -> Self {
+        // Scramble the seed with splitmix64, since xorshift requires a nonzero state.
+        let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        state ^= state >> 31;
+        Self {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a uniformly distributed number in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a number in `[0, bound)`, distributed uniformly up to a negligible bias.
+    /// The bound must be positive.
+    pub(crate) fn next_below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Bound must be positive");
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
 }
@@ -0,0 +1,1452 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    algorithms::{
+        adjacency_lists, all_simple_paths, astar, astar_edge_weight, average_out_degree,
+        betweenness_centrality, bfs_tree, bfs_with_distance, bipartite_coloring,
+        clustering_coefficients, complement, compute_in_degrees, condensation,
+        connected_component_count, contract_edge, count_triangles, cut_edges, diameter, dijkstra,
+        edge_ids_by_data, edge_multiplicity, edges_where, filter_nodes, find_node, floyd_warshall,
+        fold_out_edges, iddfs, is_acyclic, line_graph, max_flow, max_out_degree,
+        merge_parallel_edges, minimum_spanning_tree, multi_source_reachable, out_degree_sequence,
+        out_strength, pagerank, parallel_edge_groups, random_walk, shortest_path_unweighted,
+        sink_nodes, source_nodes, split_components, statistics, strongly_connected_components,
+        topological_iter, transitive_closure, CycleError,
+    },
+    generators::{complete_graph, cycle_graph, path_graph},
+    graph::{Edge, EdgeRef, ForwardNavigableGraph, Graph, MutableGraph, Node},
+    simplegraph::{disjoint_union, SimpleGraph},
+    util::NodeBitset,
+    EdgeId, NodeId,
+};
+use std::cell::Cell;
+
+fn navigation_example() -> (SimpleGraph<char, i32>, Vec<NodeId>) {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    let n3 = simple_graph.add_node(Node::new('d'));
+    let n4 = simple_graph.add_node(Node::new('e'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n3, 5)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n4, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 4)).unwrap();
+    simple_graph.add_edge(Edge::new(n3, n3, 6)).unwrap();
+    (simple_graph, vec![n0, n1, n2, n3, n4])
+}
+
+#[test]
+fn test_adjacency_lists() {
+    let (simple_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let adjacency = adjacency_lists(&adjacency_array);
+    assert_eq!(adjacency.len(), 5);
+    for id in adjacency_array.node_id_iter() {
+        let expected: Vec<_> = adjacency_array
+            .out_edges(id)
+            .map(|edge_id| adjacency_array.edge_end(edge_id))
+            .collect();
+        assert_eq!(adjacency[<usize>::from(id)], expected);
+    }
+    assert_eq!(adjacency[1], vec![nodes[0], nodes[4], nodes[2]]);
+    assert!(adjacency[4].is_empty());
+}
+
+#[test]
+fn test_is_acyclic_dag() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    let n3 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n3, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n3, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n3, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert!(is_acyclic(&adjacency_array));
+}
+
+#[test]
+fn test_is_acyclic_self_loop() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert!(!is_acyclic(&adjacency_array));
+}
+
+#[test]
+fn test_is_acyclic_cycle() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    let n3 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n3, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n3, n1, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert!(!is_acyclic(&adjacency_array));
+}
+
+fn shortest_path_example() -> (SimpleGraph<(), ()>, Vec<NodeId>) {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..6)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[3], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[4], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[4], nodes[3], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[5], nodes[0], ()))
+        .unwrap();
+    (simple_graph, nodes)
+}
+
+#[test]
+fn test_shortest_path_unweighted_direct_edge() {
+    let (simple_graph, nodes) = shortest_path_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[0], nodes[1]),
+        Some(vec![nodes[0], nodes[1]])
+    );
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[0], nodes[0]),
+        Some(vec![nodes[0]])
+    );
+}
+
+#[test]
+fn test_shortest_path_unweighted_multi_hop() {
+    let (simple_graph, nodes) = shortest_path_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[0], nodes[3]),
+        Some(vec![nodes[0], nodes[4], nodes[3]])
+    );
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[5], nodes[2]),
+        Some(vec![nodes[5], nodes[0], nodes[1], nodes[2]])
+    );
+}
+
+#[test]
+fn test_shortest_path_unweighted_unreachable() {
+    let (simple_graph, nodes) = shortest_path_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[3], nodes[0]),
+        None
+    );
+    assert_eq!(
+        shortest_path_unweighted(&adjacency_array, nodes[0], nodes[5]),
+        None
+    );
+}
+
+#[test]
+fn test_edge_multiplicity() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 'b')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 'c')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 'd')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 'e')).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(edge_multiplicity(&adjacency_array, n0, n2), 0);
+    assert_eq!(edge_multiplicity(&adjacency_array, n2, n1), 0);
+    assert_eq!(edge_multiplicity(&adjacency_array, n0, n1), 1);
+    assert_eq!(edge_multiplicity(&adjacency_array, n1, n0), 1);
+    assert_eq!(edge_multiplicity(&adjacency_array, n1, n2), 3);
+}
+
+#[test]
+fn test_out_degree_sequence() {
+    let (simple_graph, _) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(out_degree_sequence(&adjacency_array), vec![1, 3, 1, 1, 0]);
+    assert_eq!(max_out_degree(&adjacency_array), 3);
+}
+
+#[test]
+fn test_edges_where() {
+    let (simple_graph, _) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let heavy_edges: Vec<_> = edges_where(&adjacency_array, |&weight| weight > 3)
+        .map(|id| *adjacency_array.edge_data(id))
+        .collect();
+    assert_eq!(heavy_edges, vec![4, 5, 6]);
+
+    let heavy_edges: Vec<_> = edges_where(&simple_graph, |&weight| weight > 3)
+        .map(|id| *simple_graph.edge_data(id))
+        .collect();
+    assert_eq!(heavy_edges, vec![5, 4, 6]);
+    assert_eq!(
+        edges_where(&simple_graph, |&weight| weight > 6).next(),
+        None
+    );
+}
+
+#[test]
+fn test_line_graph_path() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    let e0 = simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], ()))
+        .unwrap();
+    let e1 = simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], ()))
+        .unwrap();
+    let e2 = simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[3], ()))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let line_graph = line_graph(&adjacency_array);
+    assert_eq!(line_graph.node_len(), 3);
+    let line_nodes: Vec<_> = line_graph.node_id_iter().collect();
+    let line_node_data: Vec<_> = line_nodes
+        .iter()
+        .map(|&id| *line_graph.node_data(id))
+        .collect();
+    assert_eq!(line_node_data, vec![e0, e1, e2]);
+
+    let line_edges: Vec<_> = line_graph
+        .edge_id_iter()
+        .map(|id| line_graph.edge(id))
+        .collect();
+    assert_eq!(
+        line_edges,
+        vec![
+            EdgeRef::new(line_nodes[0], line_nodes[1], &()),
+            EdgeRef::new(line_nodes[1], line_nodes[2], &()),
+        ]
+    );
+}
+
+#[test]
+fn test_fold_out_edges() {
+    let (simple_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let sum = |node| {
+        fold_out_edges(&adjacency_array, node, 0, |sum, id| {
+            sum + adjacency_array.edge_data(id)
+        })
+    };
+    assert_eq!(sum(nodes[0]), 1);
+    assert_eq!(sum(nodes[1]), 9);
+    assert_eq!(sum(nodes[3]), 6);
+    assert_eq!(sum(nodes[4]), 0);
+}
+
+#[test]
+fn test_source_and_sink_nodes() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..6)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[2], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[3], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[4], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[4], ()))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        source_nodes(&adjacency_array),
+        vec![nodes[0], nodes[1], nodes[5]]
+    );
+    assert_eq!(
+        sink_nodes(&adjacency_array),
+        vec![nodes[3], nodes[4], nodes[5]]
+    );
+}
+
+#[test]
+fn test_out_strength() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, 0.5)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 1.25)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 2.0)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n0, 0.25)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        out_strength(&adjacency_array, |&weight| weight),
+        vec![2.0, 2.0, 0.0, 0.0]
+    );
+    assert_eq!(
+        out_strength(&adjacency_array, |&weight| weight * 2.0),
+        vec![4.0, 4.0, 0.0, 0.0]
+    );
+}
+
+fn astar_example() -> (SimpleGraph<(i64, i64), u64>, Vec<NodeId>) {
+    // A row of nodes from x = -3 to x = 3, connected in both directions with unit costs.
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (-3..=3)
+        .map(|x| simple_graph.add_node(Node::new((x, 0))))
+        .collect();
+    for window in nodes.windows(2) {
+        simple_graph
+            .add_edge(Edge::new(window[1], window[0], 1))
+            .unwrap();
+        simple_graph
+            .add_edge(Edge::new(window[0], window[1], 1))
+            .unwrap();
+    }
+    // An expensive shortcut from the middle to the right end.
+    simple_graph
+        .add_edge(Edge::new(nodes[3], nodes[6], 5))
+        .unwrap();
+    (simple_graph, nodes)
+}
+
+#[test]
+fn test_astar_zero_heuristic() {
+    let (simple_graph, nodes) = astar_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        astar(&adjacency_array, nodes[3], nodes[6], |&w| w, |_| 0),
+        Some((3, vec![nodes[3], nodes[4], nodes[5], nodes[6]]))
+    );
+    assert_eq!(
+        astar(&adjacency_array, nodes[3], nodes[3], |&w| w, |_| 0),
+        Some((0, vec![nodes[3]]))
+    );
+}
+
+#[test]
+fn test_astar_heuristic_prunes_search() {
+    let (simple_graph, nodes) = astar_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    let goal = nodes[6];
+    let goal_x = adjacency_array.node_data(goal).0;
+
+    let zero_calls = Cell::new(0);
+    let dijkstra = astar(
+        &adjacency_array,
+        nodes[3],
+        goal,
+        |&w| w,
+        |_| {
+            zero_calls.set(zero_calls.get() + 1);
+            0
+        },
+    );
+    let distance_calls = Cell::new(0);
+    let result = astar(
+        &adjacency_array,
+        nodes[3],
+        goal,
+        |&w| w,
+        |id| {
+            distance_calls.set(distance_calls.get() + 1);
+            (goal_x - adjacency_array.node_data(id).0).unsigned_abs()
+        },
+    );
+
+    assert_eq!(result, dijkstra);
+    assert!(
+        distance_calls.get() < zero_calls.get(),
+        "{} >= {}",
+        distance_calls.get(),
+        zero_calls.get()
+    );
+}
+
+#[test]
+fn test_astar_unreachable() {
+    let (mut simple_graph, _) = astar_example();
+    let isolated = simple_graph.add_node(Node::new((10, 10)));
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        astar(&adjacency_array, NodeId::from(0), isolated, |&w| w, |_| 0),
+        None
+    );
+}
+
+#[test]
+fn test_parallel_edge_groups() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n1, n0, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 'b')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 'c')).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let groups = parallel_edge_groups(&adjacency_array);
+    assert_eq!(groups.len(), 1);
+    let group_data: Vec<_> = groups[0]
+        .iter()
+        .map(|&id| *adjacency_array.edge_data(id))
+        .collect();
+    assert_eq!(group_data, vec!['a', 'c']);
+}
+
+#[test]
+fn test_transitive_closure_path() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for window in nodes.windows(2) {
+        simple_graph
+            .add_edge(Edge::new(window[0], window[1], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let closure = transitive_closure(&adjacency_array);
+    assert_eq!(closure.len(), 4);
+    for (index, reachable) in closure.iter().enumerate() {
+        for (other_index, &other) in nodes.iter().enumerate() {
+            assert_eq!(reachable.contains(other), other_index >= index);
+        }
+    }
+}
+
+#[test]
+fn test_find_node() {
+    let (simple_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        find_node(&adjacency_array, |&data| data == 'c'),
+        Some(nodes[2])
+    );
+    assert_eq!(
+        find_node(&simple_graph, |&data| data == 'e'),
+        Some(nodes[4])
+    );
+    assert_eq!(find_node(&adjacency_array, |&data| data == 'z'), None);
+}
+
+#[test]
+fn test_dijkstra_edge_weight() {
+    let mut simple_graph: SimpleGraph<(), u32> = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], 7))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[2], 2))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[1], 3))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[3], 1))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        dijkstra(&adjacency_array, nodes[0], nodes[3]),
+        Some((6.0, vec![nodes[0], nodes[2], nodes[1], nodes[3]]))
+    );
+    assert_eq!(dijkstra(&adjacency_array, nodes[3], nodes[0]), None);
+    assert_eq!(
+        dijkstra(&adjacency_array, nodes[0], nodes[3]).map(|(cost, _)| cost as u64),
+        astar(&adjacency_array, nodes[0], nodes[3], |&w| w.into(), |_| 0).map(|(cost, _)| cost)
+    );
+}
+
+#[test]
+fn test_astar_edge_weight() {
+    let (simple_graph, nodes) = astar_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    let goal = nodes[6];
+    let goal_x = adjacency_array.node_data(goal).0;
+
+    let result = astar_edge_weight(&adjacency_array, nodes[3], goal, |id| {
+        (goal_x - adjacency_array.node_data(id).0).abs() as f64
+    });
+    assert_eq!(
+        result,
+        Some((3.0, vec![nodes[3], nodes[4], nodes[5], nodes[6]]))
+    );
+    assert_eq!(result, dijkstra(&adjacency_array, nodes[3], goal));
+    assert_eq!(
+        astar_edge_weight(&adjacency_array, nodes[3], nodes[3], |_| 0.0),
+        Some((0.0, vec![nodes[3]]))
+    );
+}
+
+#[test]
+fn test_merge_parallel_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 5)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 3)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let merged = merge_parallel_edges(&adjacency_array, |a, b| a + b);
+    assert_eq!(merged.node_len(), 2);
+    assert_eq!(merged.node_data(n1), &'b');
+    let edges: Vec<_> = merged.edge_id_iter().map(|id| merged.edge(id)).collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(n0, n1, &2),
+            EdgeRef::new(n1, n1, &5),
+            EdgeRef::new(n1, n0, &3),
+        ]
+    );
+}
+
+#[test]
+fn test_multi_source_reachable() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..7)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // Two components 0 -> 1 -> 2 and 3 -> 4 <- 5, and the isolated node 6.
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[3], nodes[4], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[5], nodes[4], ()))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let reachable = multi_source_reachable(&adjacency_array, &[nodes[0], nodes[3]]);
+    let reachable: Vec<_> = nodes.iter().map(|&id| reachable.contains(id)).collect();
+    assert_eq!(reachable, vec![true, true, true, true, true, false, false]);
+
+    let reachable = multi_source_reachable(&adjacency_array, &[]);
+    assert!(nodes.iter().all(|&id| !reachable.contains(id)));
+}
+
+#[test]
+fn test_condensation() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // The cycles 0 <-> 1 and 2 -> 3 -> 4 -> 2, connected by the two bridges 1 -> 2 and 0 -> 3.
+    for &(start, end) in &[(0, 1), (1, 0), (2, 3), (3, 4), (4, 2), (1, 2), (0, 3)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let (condensed, component) = condensation(&adjacency_array);
+    assert_eq!(component, vec![0, 0, 1, 1, 1]);
+    assert_eq!(condensed.node_len(), 2);
+    assert_eq!(
+        condensed.node_data(NodeId::from(0usize)),
+        &vec![nodes[0], nodes[1]]
+    );
+    assert_eq!(
+        condensed.node_data(NodeId::from(1usize)),
+        &vec![nodes[2], nodes[3], nodes[4]]
+    );
+    assert_eq!(condensed.edge_len(), 1);
+    let edge = condensed.edge(EdgeId::from(0usize));
+    assert_eq!(
+        (edge.start(), edge.end()),
+        (NodeId::from(0usize), NodeId::from(1usize))
+    );
+    assert!(is_acyclic(&AdjacencyArray::from(&condensed)));
+}
+
+#[test]
+fn test_strongly_connected_components_topological_order() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // 3 -> 2 -> 1 -> 0, so the component order is the reverse of the node order.
+    for &(start, end) in &[(3, 2), (2, 1), (1, 0)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+
+    assert_eq!(
+        strongly_connected_components(&AdjacencyArray::from(&simple_graph)),
+        vec![3, 2, 1, 0]
+    );
+}
+
+#[test]
+fn test_diameter() {
+    let path = AdjacencyArray::from(&path_graph(5));
+    assert_eq!(diameter(&path), Some(4));
+
+    let cycle = AdjacencyArray::from(&cycle_graph(6));
+    assert_eq!(diameter(&cycle), Some(5));
+
+    let isolated = AdjacencyArray::<(), ()>::with_node_count(3);
+    assert_eq!(diameter(&isolated), Some(0));
+
+    let empty = AdjacencyArray::<(), ()>::with_node_count(0);
+    assert_eq!(diameter(&empty), None);
+}
+
+#[test]
+fn test_cut_edges() {
+    let complete = AdjacencyArray::from(&complete_graph(5));
+    let mut set = NodeBitset::new(complete.node_len());
+    set.set(NodeId::from(0usize));
+    set.set(NodeId::from(3usize));
+
+    let cut: Vec<_> = cut_edges(&complete, &set).collect();
+    // Each of the two members has an edge to each of the three non-members.
+    assert_eq!(cut.len(), 6);
+    for &edge_id in &cut {
+        assert!(set.contains(complete.edge_start(edge_id)));
+        assert!(!set.contains(complete.edge_end(edge_id)));
+    }
+
+    let (navigation_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&navigation_graph);
+    let mut set = NodeBitset::new(adjacency_array.node_len());
+    for id in adjacency_array.node_id_iter() {
+        set.set(id);
+    }
+    assert_eq!(cut_edges(&adjacency_array, &set).count(), 0);
+    set.clear(nodes[0]);
+    let cut: Vec<_> = cut_edges(&adjacency_array, &set)
+        .map(|edge_id| adjacency_array.edge_endpoints(edge_id))
+        .collect();
+    assert_eq!(cut, vec![(nodes[1], nodes[0])]);
+}
+
+#[test]
+fn test_complement() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], 'x'))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[2], 'y'))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let complement = complement(&adjacency_array);
+    assert_eq!(complement.node_len(), 3);
+    for id in complement.node_id_iter() {
+        assert_eq!(complement.node_data(id), simple_graph.node_data(id));
+    }
+    let edges: Vec<_> = complement
+        .edge_id_iter()
+        .map(|id| complement.edge_endpoints(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            (nodes[0], nodes[2]),
+            (nodes[1], nodes[0]),
+            (nodes[1], nodes[2]),
+            (nodes[2], nodes[0]),
+            (nodes[2], nodes[1]),
+        ]
+    );
+}
+
+#[test]
+fn test_max_flow() {
+    // The flow network from Cormen et al., Introduction to Algorithms, with a maximum flow of 23.
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..6)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    let edges = [
+        (0, 1, 16),
+        (0, 2, 13),
+        (2, 1, 4),
+        (1, 3, 12),
+        (3, 2, 9),
+        (2, 4, 14),
+        (4, 3, 7),
+        (3, 5, 20),
+        (4, 5, 4),
+    ];
+    for &(start, end, capacity) in &edges {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], capacity))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(max_flow(&adjacency_array, nodes[0], nodes[5], |&c| c), 23);
+    assert_eq!(max_flow(&adjacency_array, nodes[5], nodes[0], |&c| c), 0);
+    assert_eq!(max_flow(&adjacency_array, nodes[0], nodes[0], |&c| c), 0);
+    assert_eq!(max_flow(&adjacency_array, nodes[0], nodes[5], |_| 1), 2);
+}
+
+#[test]
+fn test_minimum_spanning_tree() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    let edges = [
+        (0, 1, 1),
+        (2, 1, 2),
+        (0, 2, 3),
+        (3, 2, 4),
+        (1, 3, 5),
+        (3, 0, 6),
+        (3, 3, 0),
+    ];
+    let edge_ids: Vec<_> = edges
+        .iter()
+        .map(|&(start, end, weight)| {
+            simple_graph
+                .add_edge(Edge::new(nodes[start], nodes[end], weight))
+                .unwrap()
+        })
+        .collect();
+
+    let tree = minimum_spanning_tree(&simple_graph, |&weight| weight);
+    assert_eq!(tree, vec![edge_ids[0], edge_ids[1], edge_ids[3]]);
+    let total_weight: u64 = tree.iter().map(|&id| *simple_graph.edge_data(id)).sum();
+    assert_eq!(total_weight, 7);
+
+    let mut forest_graph = SimpleGraph::new();
+    let forest_nodes: Vec<_> = (0..4)
+        .map(|_| forest_graph.add_node(Node::new(())))
+        .collect();
+    forest_graph
+        .add_edge(Edge::new(forest_nodes[0], forest_nodes[1], 2))
+        .unwrap();
+    forest_graph
+        .add_edge(Edge::new(forest_nodes[3], forest_nodes[2], 1))
+        .unwrap();
+    assert_eq!(
+        minimum_spanning_tree(&forest_graph, |&weight| weight).len(),
+        2
+    );
+}
+
+#[test]
+fn test_bipartite_coloring() {
+    let even_cycle = AdjacencyArray::from(&cycle_graph(6));
+    let coloring = bipartite_coloring(&even_cycle).unwrap();
+    assert_eq!(coloring, vec![false, true, false, true, false, true]);
+    for id in even_cycle.edge_id_iter() {
+        let (start, end) = even_cycle.edge_endpoints(id);
+        assert_ne!(coloring[<usize>::from(start)], coloring[<usize>::from(end)]);
+    }
+
+    let odd_cycle = AdjacencyArray::from(&cycle_graph(5));
+    assert_eq!(bipartite_coloring(&odd_cycle), None);
+
+    // The edges point against the BFS direction, so they are only found when treated as undirected.
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[0], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[0], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[1], ()))
+        .unwrap();
+    let triangle = AdjacencyArray::from(&simple_graph);
+    assert_eq!(bipartite_coloring(&triangle), None);
+
+    let mut simple_graph = SimpleGraph::new();
+    let node = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(node, node, ())).unwrap();
+    assert_eq!(
+        bipartite_coloring(&AdjacencyArray::from(&simple_graph)),
+        None
+    );
+}
+
+#[test]
+fn test_pagerank() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // Node 2 is dangling.
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[2], ()))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], ()))
+        .unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let assert_close = |actual: &[f64], expected: &[f64]| {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    };
+
+    assert_close(&pagerank(&adjacency_array, 0.5, 0), &[1.0 / 3.0; 3]);
+    assert_close(
+        &pagerank(&adjacency_array, 0.5, 1),
+        &[8.0 / 36.0, 11.0 / 36.0, 17.0 / 36.0],
+    );
+
+    let ranks = pagerank(&adjacency_array, 0.85, 50);
+    assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(ranks[0] < ranks[1] && ranks[1] < ranks[2]);
+
+    let cycle = AdjacencyArray::from(&cycle_graph(4));
+    assert_close(&pagerank(&cycle, 0.85, 20), &[0.25; 4]);
+
+    let empty = AdjacencyArray::<(), ()>::with_node_count(0);
+    assert!(pagerank(&empty, 0.85, 20).is_empty());
+}
+
+#[test]
+fn test_count_triangles() {
+    let triangle = AdjacencyArray::from(&cycle_graph(3));
+    assert_eq!(count_triangles(&triangle), 1);
+
+    let square = AdjacencyArray::from(&cycle_graph(4));
+    assert_eq!(count_triangles(&square), 0);
+
+    let k4 = AdjacencyArray::from(&complete_graph(4));
+    assert_eq!(count_triangles(&k4), 4);
+
+    let k5 = AdjacencyArray::from(&complete_graph(5));
+    assert_eq!(count_triangles(&k5), 10);
+
+    // Parallel edges, opposite edges and self-loops do not create additional triangles.
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end) in &[(0, 1), (1, 0), (0, 1), (1, 2), (2, 0), (2, 2)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    assert_eq!(count_triangles(&AdjacencyArray::from(&simple_graph)), 1);
+}
+
+#[test]
+fn test_clustering_coefficients() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // The triangle 0, 1, 2, with node 3 attached to node 2, and the isolated node 4.
+    for &(start, end) in &[(0, 1), (1, 2), (2, 0), (3, 2)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let coefficients = clustering_coefficients(&adjacency_array);
+    assert_eq!(coefficients, vec![1.0, 1.0, 1.0 / 3.0, 0.0, 0.0]);
+
+    let k4 = AdjacencyArray::from(&complete_graph(4));
+    assert_eq!(clustering_coefficients(&k4), vec![1.0; 4]);
+}
+
+#[test]
+fn test_iddfs() {
+    let path = AdjacencyArray::from(&path_graph(6));
+    let nodes: Vec<_> = path.node_id_iter().collect();
+    assert_eq!(
+        iddfs(&path, nodes[0], nodes[3], 3),
+        Some(nodes[..4].to_vec())
+    );
+    assert_eq!(iddfs(&path, nodes[0], nodes[4], 3), None);
+    assert_eq!(iddfs(&path, nodes[4], nodes[0], 10), None);
+    assert_eq!(iddfs(&path, nodes[2], nodes[2], 0), Some(vec![nodes[2]]));
+
+    // The shortest path is found even if depth-first search would explore a longer one first.
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end) in &[(0, 1), (1, 2), (2, 3), (0, 3), (2, 0)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    assert_eq!(
+        iddfs(&adjacency_array, nodes[0], nodes[3], 5),
+        Some(vec![nodes[0], nodes[3]])
+    );
+    assert_eq!(
+        iddfs(&adjacency_array, nodes[1], nodes[3], 5),
+        Some(vec![nodes[1], nodes[2], nodes[3]])
+    );
+    assert_eq!(iddfs(&adjacency_array, nodes[1], nodes[3], 1), None);
+}
+
+#[test]
+fn test_statistics() {
+    let (simple_graph, _) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let stats = statistics(&adjacency_array);
+    assert_eq!(stats.node_len, 5);
+    assert_eq!(stats.edge_len, 6);
+    assert_eq!(stats.max_out_degree, 3);
+    assert_eq!(stats.min_out_degree, 0);
+    assert!((stats.average_out_degree - 1.2).abs() < 1e-12);
+    assert_eq!(stats.self_loop_len, 1);
+    assert_eq!(stats.isolated_node_len, 0);
+
+    let isolated = statistics(&AdjacencyArray::<(), ()>::with_node_count(3));
+    assert_eq!(isolated.isolated_node_len, 3);
+    assert_eq!(isolated.average_out_degree, 0.0);
+
+    let empty = statistics(&AdjacencyArray::<(), ()>::with_node_count(0));
+    assert_eq!(
+        (empty.node_len, empty.max_out_degree, empty.min_out_degree),
+        (0, 0, 0)
+    );
+    assert_eq!(empty.average_out_degree, 0.0);
+}
+
+#[test]
+fn test_filter_nodes() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    for &(start, end, data) in &[
+        (0, 1, 'a'),
+        (0, 2, 'b'),
+        (2, 4, 'c'),
+        (3, 4, 'd'),
+        (4, 0, 'e'),
+    ] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], data))
+            .unwrap();
+    }
+
+    let (filtered, node_map) = filter_nodes(&simple_graph, |_, &data| data % 2 == 0);
+    let new_nodes: Vec<_> = (0..3).map(NodeId::from).collect();
+    assert_eq!(
+        node_map,
+        vec![
+            Some(new_nodes[0]),
+            None,
+            Some(new_nodes[1]),
+            None,
+            Some(new_nodes[2])
+        ]
+    );
+    let node_data: Vec<_> = filtered
+        .node_id_iter()
+        .map(|id| *filtered.node_data(id))
+        .collect();
+    assert_eq!(node_data, vec![0, 2, 4]);
+    let edges: Vec<_> = filtered
+        .edge_id_iter()
+        .map(|id| filtered.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(new_nodes[0], new_nodes[1], &'b'),
+            EdgeRef::new(new_nodes[1], new_nodes[2], &'c'),
+            EdgeRef::new(new_nodes[2], new_nodes[0], &'e'),
+        ]
+    );
+}
+
+#[test]
+fn test_compute_in_degrees() {
+    let (simple_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let in_degrees = compute_in_degrees(&adjacency_array);
+    assert_eq!(in_degrees, vec![1, 1, 1, 2, 1]);
+    let bidirectional = adjacency_array.with_backward_index();
+    for &id in &nodes {
+        assert_eq!(
+            in_degrees[<usize>::from(id)] as usize,
+            bidirectional.in_edges(id).count()
+        );
+    }
+}
+
+#[test]
+fn test_random_walk() {
+    let complete = AdjacencyArray::from(&complete_graph(5));
+    let start = NodeId::from(2usize);
+    let walk: Vec<_> = random_walk(&complete, start, 42).take(50).collect();
+    let repeated_walk: Vec<_> = random_walk(&complete, start, 42).take(50).collect();
+    assert_eq!(walk, repeated_walk);
+    assert_eq!(walk.len(), 50);
+    assert_eq!(walk[0], start);
+    for window in walk.windows(2) {
+        assert!(complete
+            .out_edges(window[0])
+            .any(|edge_id| complete.edge_end(edge_id) == window[1]));
+    }
+    let visited: std::collections::HashSet<_> = walk.iter().collect();
+    assert_eq!(visited.len(), 5);
+
+    let path = AdjacencyArray::from(&path_graph(4));
+    let nodes: Vec<_> = path.node_id_iter().collect();
+    assert_eq!(random_walk(&path, nodes[0], 7).collect::<Vec<_>>(), nodes);
+    assert_eq!(
+        random_walk(&path, nodes[3], 7).collect::<Vec<_>>(),
+        vec![nodes[3]]
+    );
+}
+
+#[test]
+fn test_bfs_with_distance() {
+    let path = AdjacencyArray::from(&path_graph(5));
+    let nodes: Vec<_> = path.node_id_iter().collect();
+    let order: Vec<_> = bfs_with_distance(&path, nodes[0]).collect();
+    assert_eq!(order.len(), 5);
+    for (index, &(node, distance)) in order.iter().enumerate() {
+        assert_eq!(node, nodes[index]);
+        assert_eq!(distance as usize, index);
+    }
+    assert_eq!(
+        bfs_with_distance(&path, nodes[3]).collect::<Vec<_>>(),
+        vec![(nodes[3], 0), (nodes[4], 1)]
+    );
+
+    let (simple_graph, nodes) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    let order: Vec<_> = bfs_with_distance(&adjacency_array, nodes[0]).collect();
+    assert_eq!(
+        order,
+        vec![
+            (nodes[0], 0),
+            (nodes[1], 1),
+            (nodes[4], 2),
+            (nodes[2], 2),
+            (nodes[3], 3)
+        ]
+    );
+}
+
+#[test]
+fn test_contract_edge() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    let e0 = simple_graph
+        .add_edge(Edge::new(nodes[0], nodes[1], 'a'))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[2], 'b'))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[0], 'c'))
+        .unwrap();
+    simple_graph
+        .add_edge(Edge::new(nodes[1], nodes[0], 'd'))
+        .unwrap();
+    let e4 = simple_graph
+        .add_edge(Edge::new(nodes[2], nodes[2], 'e'))
+        .unwrap();
+
+    let (contracted, node_map) = contract_edge(&simple_graph, e0);
+    let new_nodes: Vec<_> = (0..2).map(NodeId::from).collect();
+    assert_eq!(node_map, vec![new_nodes[0], new_nodes[0], new_nodes[1]]);
+    let node_data: Vec<_> = contracted
+        .node_id_iter()
+        .map(|id| *contracted.node_data(id))
+        .collect();
+    assert_eq!(node_data, vec![0, 2]);
+    let edges: Vec<_> = contracted
+        .edge_id_iter()
+        .map(|id| contracted.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(new_nodes[0], new_nodes[1], &'b'),
+            EdgeRef::new(new_nodes[1], new_nodes[0], &'c'),
+            EdgeRef::new(new_nodes[1], new_nodes[1], &'e'),
+        ]
+    );
+
+    let (contracted, node_map) = contract_edge(&simple_graph, e4);
+    assert_eq!(node_map, nodes);
+    assert_eq!(contracted.node_len(), 3);
+    assert_eq!(contracted.edge_len(), 4);
+}
+
+#[test]
+fn test_all_simple_paths() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    // Two paths from 0 to 3, a cycle back to the start and a dead end at node 4.
+    for &(start, end) in &[(0, 1), (1, 3), (0, 2), (2, 1), (1, 0), (2, 4)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        all_simple_paths(&adjacency_array, nodes[0], nodes[3], 5),
+        vec![
+            vec![nodes[0], nodes[1], nodes[3]],
+            vec![nodes[0], nodes[2], nodes[1], nodes[3]],
+        ]
+    );
+    assert_eq!(
+        all_simple_paths(&adjacency_array, nodes[0], nodes[3], 2),
+        vec![vec![nodes[0], nodes[1], nodes[3]]]
+    );
+    assert!(all_simple_paths(&adjacency_array, nodes[0], nodes[3], 1).is_empty());
+    assert!(all_simple_paths(&adjacency_array, nodes[3], nodes[0], 5).is_empty());
+    assert_eq!(
+        all_simple_paths(&adjacency_array, nodes[4], nodes[4], 0),
+        vec![vec![nodes[4]]]
+    );
+}
+
+#[test]
+fn test_average_out_degree() {
+    let (simple_graph, _) = navigation_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    assert!((average_out_degree(&adjacency_array) - 1.2).abs() < 1e-12);
+
+    let empty = AdjacencyArray::<(), ()>::with_node_count(0);
+    assert_eq!(average_out_degree(&empty), 0.0);
+}
+
+#[test]
+fn test_floyd_warshall() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end, weight) in &[(0, 1, 4), (0, 2, 1), (2, 1, -2), (1, 0, 3), (0, 1, 5)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], weight))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let distances = floyd_warshall(&adjacency_array, |&weight| weight);
+    assert_eq!(
+        distances,
+        vec![
+            vec![Some(0), Some(-1), Some(1), None],
+            vec![Some(3), Some(0), Some(4), None],
+            vec![Some(1), Some(-2), Some(0), None],
+            vec![None, None, None, Some(0)],
+        ]
+    );
+
+    let mut negative_cycle = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|_| negative_cycle.add_node(Node::new(())))
+        .collect();
+    for &(start, end, weight) in &[(0, 1, 1), (1, 0, -2), (1, 2, 1)] {
+        negative_cycle
+            .add_edge(Edge::new(nodes[start], nodes[end], weight))
+            .unwrap();
+    }
+    let distances = floyd_warshall(&AdjacencyArray::from(&negative_cycle), |&weight| weight);
+    assert_eq!(distances[0][0], None);
+    assert_eq!(distances[1][1], None);
+    assert_eq!(distances[2][2], Some(0));
+}
+
+#[test]
+fn test_floyd_warshall_overflow() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end, weight) in &[
+        (0, 1, i64::MAX),
+        (1, 2, i64::MAX),
+        (0, 2, 5),
+        (2, 4, i64::MAX),
+        (3, 3, i64::MIN / 2 - 1),
+    ] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], weight))
+            .unwrap();
+    }
+
+    let distances = floyd_warshall(&AdjacencyArray::from(&simple_graph), |&weight| weight);
+    assert_eq!(distances[0][1], Some(i64::MAX));
+    // The overflowing path over node 1 must not beat the direct edge.
+    assert_eq!(distances[0][2], Some(5));
+    assert_eq!(distances[1][4], Some(i64::MAX));
+    assert_eq!(distances[0][0], Some(0));
+    assert_eq!(distances[3][3], None);
+}
+
+#[test]
+fn test_edge_ids_by_data() {
+    let mut graph = SimpleGraph::new();
+    let n0 = graph.add_node(Node::new(()));
+    let n1 = graph.add_node(Node::new(()));
+    let n2 = graph.add_node(Node::new(()));
+    let e0 = graph.add_edge(Edge::new(n0, n1, 5)).unwrap();
+    let e1 = graph.add_edge(Edge::new(n1, n2, 2)).unwrap();
+    let e2 = graph.add_edge(Edge::new(n2, n0, 5)).unwrap();
+    let e3 = graph.add_edge(Edge::new(n0, n2, 1)).unwrap();
+    let e4 = graph.add_edge(Edge::new(n1, n0, 3)).unwrap();
+
+    assert_eq!(edge_ids_by_data(&graph), vec![e3, e1, e4, e0, e2]);
+    assert_eq!(
+        edge_ids_by_data(&SimpleGraph::<(), u32>::new()),
+        Vec::<EdgeId>::new()
+    );
+}
+
+#[test]
+fn test_betweenness_centrality() {
+    let mut star = SimpleGraph::new();
+    let center = star.add_node(Node::new(()));
+    for _ in 0..4 {
+        let leaf = star.add_node(Node::new(()));
+        star.add_edge(Edge::new(center, leaf, ())).unwrap();
+        star.add_edge(Edge::new(leaf, center, ())).unwrap();
+    }
+    let centrality = betweenness_centrality(&AdjacencyArray::from(&star));
+    assert_eq!(centrality, vec![12.0, 0.0, 0.0, 0.0, 0.0]);
+
+    // Two shortest paths from n0 to n3, one via n1 and one via n2.
+    let mut diamond = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4).map(|_| diamond.add_node(Node::new(()))).collect();
+    for &(start, end) in &[(0, 1), (0, 2), (1, 3), (2, 3)] {
+        diamond
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let centrality = betweenness_centrality(&AdjacencyArray::from(&diamond));
+    assert_eq!(centrality, vec![0.0, 0.5, 0.5, 0.0]);
+
+    let path = betweenness_centrality(&AdjacencyArray::from(&path_graph(4)));
+    assert_eq!(path, vec![0.0, 2.0, 2.0, 0.0]);
+}
+
+#[test]
+fn test_bfs_tree() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..6)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end) in &[(0, 1), (1, 2), (2, 3), (0, 4), (4, 3), (3, 0)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let predecessors = bfs_tree(&adjacency_array, nodes[0]);
+    assert_eq!(
+        predecessors,
+        vec![
+            None,
+            Some(nodes[0]),
+            Some(nodes[1]),
+            Some(nodes[4]),
+            Some(nodes[0]),
+            None
+        ]
+    );
+
+    let mut path = vec![nodes[3]];
+    while let Some(predecessor) = predecessors[<usize>::from(*path.last().unwrap())] {
+        path.push(predecessor);
+    }
+    path.reverse();
+    assert_eq!(path, vec![nodes[0], nodes[4], nodes[3]]);
+    assert_eq!(
+        Some(path),
+        shortest_path_unweighted(&adjacency_array, nodes[0], nodes[3])
+    );
+
+    assert_eq!(bfs_tree(&adjacency_array, nodes[5]), vec![None; 6]);
+}
+
+#[test]
+fn test_topological_iter() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|_| simple_graph.add_node(Node::new(())))
+        .collect();
+    for &(start, end) in &[(3, 1), (1, 0), (3, 4), (4, 0), (2, 4)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], ()))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    let order: Vec<_> = topological_iter(&adjacency_array).unwrap().collect();
+    assert_eq!(
+        order,
+        vec![nodes[2], nodes[3], nodes[1], nodes[4], nodes[0]]
+    );
+
+    let large_dag = AdjacencyArray::from(&path_graph(100_000));
+    let prefix: Vec<_> = topological_iter(&large_dag).unwrap().take(3).collect();
+    assert_eq!(prefix, (0..3).map(NodeId::from).collect::<Vec<_>>());
+
+    let cycle = AdjacencyArray::from(&cycle_graph(3));
+    assert_eq!(topological_iter(&cycle).err(), Some(CycleError));
+}
+
+#[test]
+fn test_connected_component_count() {
+    let (two_components, _) = disjoint_union(&path_graph(3), &cycle_graph(4));
+    assert_eq!(
+        connected_component_count(&AdjacencyArray::from(&two_components)),
+        2
+    );
+
+    let mut with_isolated_node = two_components;
+    with_isolated_node.add_node(Node::new(()));
+    assert_eq!(
+        connected_component_count(&AdjacencyArray::from(&with_isolated_node)),
+        3
+    );
+
+    // Edges are treated as undirected, so a node with only in-edges joins the component of its predecessors.
+    let mut in_star = SimpleGraph::new();
+    let center = in_star.add_node(Node::new(()));
+    for _ in 0..3 {
+        let leaf = in_star.add_node(Node::new(()));
+        in_star.add_edge(Edge::new(leaf, center, ())).unwrap();
+    }
+    assert_eq!(
+        connected_component_count(&AdjacencyArray::from(&in_star)),
+        1
+    );
+
+    assert_eq!(
+        connected_component_count(&AdjacencyArray::from(&SimpleGraph::<(), ()>::new())),
+        0
+    );
+}
+
+#[test]
+fn test_split_components() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|index| simple_graph.add_node(Node::new(index)))
+        .collect();
+    for &(start, end, data) in &[(0, 2, 'a'), (1, 3, 'b'), (2, 4, 'c'), (4, 0, 'd')] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], data))
+            .unwrap();
+    }
+
+    let components = split_components(&AdjacencyArray::from(&simple_graph));
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0].node_len(), 3);
+    assert_eq!(components[0].edge_len(), 3);
+    assert_eq!(components[1].node_len(), 2);
+    assert_eq!(components[1].edge_len(), 1);
+
+    let first_data: Vec<_> = components[0]
+        .node_id_iter()
+        .map(|id| *components[0].node_data(id))
+        .collect();
+    assert_eq!(first_data, vec![0, 2, 4]);
+    let n = |index: usize| NodeId::from(index);
+    let first_edges: Vec<_> = components[0]
+        .edge_id_iter()
+        .map(|id| components[0].edge(id))
+        .collect();
+    assert_eq!(
+        first_edges,
+        vec![
+            EdgeRef::new(n(0), n(1), &'a'),
+            EdgeRef::new(n(1), n(2), &'c'),
+            EdgeRef::new(n(2), n(0), &'d'),
+        ]
+    );
+    assert_eq!(
+        components[1].edge(EdgeId::from(0)),
+        EdgeRef::new(n(0), n(1), &'b')
+    );
+
+    assert!(split_components(&AdjacencyArray::from(&SimpleGraph::<(), ()>::new())).is_empty());
+}
@@ -0,0 +1,669 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    graph::{
+        Edge, EdgeRef, ForwardNavigableGraph, Graph, GraphModificationError, MutableGraph, Node,
+    },
+    simplegraph::{disjoint_union, SimpleGraph},
+    EdgeId, NodeId,
+};
+
+#[test]
+fn test_adjacency_array_from_raw_parts() {
+    let first_out = vec![0, 2, 2, 3].into_iter().map(EdgeId::from).collect();
+    let edge_ends = vec![1, 2, 0].into_iter().map(NodeId::from).collect();
+    let adjacency_array =
+        AdjacencyArray::from_raw_parts(first_out, edge_ends, vec!['a', 'b', 'c'], vec![1, 2, 3])
+            .unwrap();
+
+    let n0 = NodeId::from(0);
+    let n1 = NodeId::from(1);
+    let n2 = NodeId::from(2);
+    assert_eq!(adjacency_array.node_len(), 3);
+    assert_eq!(adjacency_array.edge_len(), 3);
+    assert_eq!(adjacency_array.node_data(n2), &'c');
+    let n0_out_edges: Vec<_> = adjacency_array
+        .out_edges(n0)
+        .map(|id| adjacency_array.edge(id))
+        .collect();
+    assert_eq!(
+        n0_out_edges,
+        vec![EdgeRef::new(n0, n1, &1), EdgeRef::new(n0, n2, &2)]
+    );
+    assert_eq!(adjacency_array.out_edges(n1).next(), None);
+    assert_eq!(
+        adjacency_array.edge(EdgeId::from(2)),
+        EdgeRef::new(n2, n0, &3)
+    );
+}
+
+#[test]
+fn test_adjacency_array_from_raw_parts_mismatched_lengths() {
+    let first_out = vec![0, 2, 2, 3].into_iter().map(EdgeId::from).collect();
+    let edge_ends = vec![1, 2, 0].into_iter().map(NodeId::from).collect();
+    let result =
+        AdjacencyArray::from_raw_parts(first_out, edge_ends, vec!['a', 'b'], vec![1, 2, 3]);
+    assert!(result.is_err());
+
+    let first_out = vec![0, 2, 2, 3].into_iter().map(EdgeId::from).collect();
+    let edge_ends = vec![1, 2].into_iter().map(NodeId::from).collect();
+    let result =
+        AdjacencyArray::from_raw_parts(first_out, edge_ends, vec!['a', 'b', 'c'], vec![1, 2]);
+    assert!(result.is_err());
+
+    let first_out = vec![0, 2, 1, 3].into_iter().map(EdgeId::from).collect();
+    let edge_ends = vec![1, 2, 0].into_iter().map(NodeId::from).collect();
+    let result =
+        AdjacencyArray::from_raw_parts(first_out, edge_ends, vec!['a', 'b', 'c'], vec![1, 2, 3]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_adjacency_array_relabel_nodes() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n1, 3)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    // a -> N2, b -> N0, c -> N1
+    let relabeled = adjacency_array.relabel_nodes(&[n2, n0, n1]);
+    assert_eq!(relabeled.check_invariants(), Ok(()));
+    assert_eq!(relabeled.node_data(n0), &'b');
+    assert_eq!(relabeled.node_data(n1), &'c');
+    assert_eq!(relabeled.node_data(n2), &'a');
+
+    assert_eq!(relabeled.out_edges(n0).next(), None);
+    let n1_out_edges: Vec<_> = relabeled
+        .out_edges(n1)
+        .map(|id| relabeled.edge(id))
+        .collect();
+    assert_eq!(n1_out_edges, vec![EdgeRef::new(n1, n0, &3)]);
+    let n2_out_edges: Vec<_> = relabeled
+        .out_edges(n2)
+        .map(|id| relabeled.edge(id))
+        .collect();
+    assert_eq!(
+        n2_out_edges,
+        vec![EdgeRef::new(n2, n0, &1), EdgeRef::new(n2, n1, &2)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_adjacency_array_relabel_nodes_not_a_permutation() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    adjacency_array.relabel_nodes(&[n1, n1]);
+}
+
+#[test]
+fn test_simple_graph_anonymous_nodes() {
+    let mut simple_graph: SimpleGraph<(), char> = SimpleGraph::new();
+    let n0 = simple_graph.add_anonymous_node();
+    let n1 = simple_graph.add_anonymous_node();
+    let n2 = simple_graph.add_anonymous_node();
+    simple_graph.add_edge(Edge::new(n0, n1, 'x')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 'y')).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(adjacency_array.node_len(), 3);
+    assert_eq!(adjacency_array.edge_len(), 2);
+    let n1_out_edges: Vec<_> = adjacency_array
+        .out_edges(n1)
+        .map(|id| adjacency_array.edge(id))
+        .collect();
+    assert_eq!(n1_out_edges, vec![EdgeRef::new(n1, n2, &'y')]);
+}
+
+#[test]
+fn test_adjacency_array_with_node_count() {
+    let adjacency_array: AdjacencyArray<(), ()> = AdjacencyArray::with_node_count(4);
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(adjacency_array.node_len(), 4);
+    assert_eq!(adjacency_array.edge_len(), 0);
+    for id in adjacency_array.node_id_iter() {
+        assert_eq!(adjacency_array.out_edges(id).next(), None);
+    }
+
+    let adjacency_array: AdjacencyArray<u8, ()> = AdjacencyArray::with_node_count(0);
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(adjacency_array.node_len(), 0);
+}
+
+#[test]
+fn test_simple_graph_disjoint_union() {
+    let mut a = SimpleGraph::new();
+    let a0 = a.add_node(Node::new('a'));
+    let a1 = a.add_node(Node::new('b'));
+    a.add_edge(Edge::new(a0, a1, 1)).unwrap();
+    let mut b = SimpleGraph::new();
+    let b0 = b.add_node(Node::new('c'));
+    let b1 = b.add_node(Node::new('d'));
+    b.add_edge(Edge::new(b1, b0, 2)).unwrap();
+    b.add_edge(Edge::new(b0, b0, 3)).unwrap();
+
+    let (union, node_map) = disjoint_union(&a, &b);
+    assert_eq!(union.node_len(), 4);
+    assert_eq!(union.edge_len(), 3);
+    assert_eq!(node_map, vec![NodeId::from(2), NodeId::from(3)]);
+    let node_data: Vec<_> = union
+        .node_id_iter()
+        .map(|id| *union.node_data(id))
+        .collect();
+    assert_eq!(node_data, vec!['a', 'b', 'c', 'd']);
+    let edges: Vec<_> = union.edge_id_iter().map(|id| union.edge(id)).collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(a0, a1, &1),
+            EdgeRef::new(node_map[1], node_map[0], &2),
+            EdgeRef::new(node_map[0], node_map[0], &3),
+        ]
+    );
+}
+
+#[test]
+fn test_adjacency_array_from_raw_parts_corrupted() {
+    let edge_ids = |ids: &[usize]| ids.iter().copied().map(EdgeId::from).collect::<Vec<_>>();
+    let node_ids = |ids: &[usize]| ids.iter().copied().map(NodeId::from).collect::<Vec<_>>();
+
+    let error = AdjacencyArray::from_raw_parts(
+        edge_ids(&[0, 2, 1]),
+        node_ids(&[1]),
+        vec![(), ()],
+        vec![()],
+    )
+    .unwrap_err();
+    assert!(error.contains("decreasing"), "{}", error);
+
+    let error = AdjacencyArray::from_raw_parts(
+        edge_ids(&[0, 1, 1]),
+        node_ids(&[2]),
+        vec![(), ()],
+        vec![()],
+    )
+    .unwrap_err();
+    assert!(error.contains("N2"), "{}", error);
+
+    let error =
+        AdjacencyArray::from_raw_parts(edge_ids(&[0, 1, 1]), node_ids(&[1]), vec![()], vec![()])
+            .unwrap_err();
+    assert!(error.contains("node data"), "{}", error);
+}
+
+#[test]
+fn test_adjacency_array_from_edges() {
+    let edges = [
+        (2, 0, 'a'),
+        (0, 1, 'b'),
+        (2, 2, 'c'),
+        (0, 2, 'd'),
+        (1, 0, 'e'),
+    ];
+    let nodes: Vec<_> = (0..3).map(NodeId::from).collect();
+    let mut simple_graph = SimpleGraph::new();
+    for data in 0..3 {
+        simple_graph.add_node(Node::new(data));
+    }
+    for &(start, end, data) in &edges {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], data))
+            .unwrap();
+    }
+    let expected = AdjacencyArray::from(&simple_graph);
+
+    let adjacency_array = AdjacencyArray::from_edges(
+        3,
+        edges
+            .iter()
+            .map(|&(start, end, data)| Edge::new(nodes[start], nodes[end], data)),
+        vec![0, 1, 2],
+    )
+    .unwrap();
+
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(adjacency_array.node_len(), expected.node_len());
+    for id in expected.node_id_iter() {
+        assert_eq!(adjacency_array.node_data(id), expected.node_data(id));
+    }
+    assert_eq!(adjacency_array.edge_len(), expected.edge_len());
+    for id in expected.edge_id_iter() {
+        assert_eq!(adjacency_array.edge(id), expected.edge(id));
+    }
+}
+
+#[test]
+fn test_adjacency_array_from_edges_invalid_endpoints() {
+    let n0 = NodeId::from(0);
+    let n2 = NodeId::from(2);
+    let result =
+        AdjacencyArray::from_edges(2, vec![Edge::new(n2, n0, ())].into_iter(), vec![(), ()]);
+    assert!(matches!(
+        result,
+        Err(GraphModificationError::StartNodeDoesNotExist)
+    ));
+    let result =
+        AdjacencyArray::from_edges(2, vec![Edge::new(n0, n2, ())].into_iter(), vec![(), ()]);
+    assert!(matches!(
+        result,
+        Err(GraphModificationError::EndNodeDoesNotExist)
+    ));
+}
+
+#[test]
+fn test_adjacency_array_from_edges_node_data_len_mismatch() {
+    let n0 = NodeId::from(0);
+    let result = AdjacencyArray::from_edges(2, vec![Edge::new(n0, n0, ())].into_iter(), vec![()]);
+    assert!(matches!(
+        result,
+        Err(GraphModificationError::NodeDataLenMismatch)
+    ));
+}
+
+#[test]
+fn test_memory_bytes() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(0u64));
+    let n1 = simple_graph.add_node(Node::new(1u64));
+    simple_graph.add_node(Node::new(2u64));
+    simple_graph.add_edge(Edge::new(n0, n1, 0u32)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 1u32)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    // At least three node data entries, two edge data entries, two edge ends and four first out entries.
+    let minimum = 3 * 8 + 2 * 4 + 2 * 4 + 4 * 4;
+    assert!(adjacency_array.memory_bytes() >= minimum);
+    assert!(adjacency_array.memory_bytes() < 10 * minimum);
+    // The backward index adds four first in entries and two in-edges.
+    let bidirectional = adjacency_array.with_backward_index();
+    assert!(bidirectional.memory_bytes() >= minimum + 4 * 4 + 2 * 4);
+    assert!(simple_graph.memory_bytes() >= 3 * 8 + 2 * 12);
+    assert_eq!(SimpleGraph::<u64, u32>::new().memory_bytes(), 0);
+}
+
+#[test]
+fn test_simple_graph_shrink_to_fit() {
+    let mut simple_graph = SimpleGraph::with_capacity(100, 100);
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let e0 = simple_graph.add_edge(Edge::new(n0, n1, 1u32)).unwrap();
+    let reserved_bytes = simple_graph.memory_bytes();
+
+    simple_graph.shrink_to_fit();
+    assert!(simple_graph.memory_bytes() < reserved_bytes);
+    assert_eq!(simple_graph.node_len(), 2);
+    assert_eq!(simple_graph.node_data(n1), &'b');
+    assert_eq!(simple_graph.edge(e0), EdgeRef::new(n0, n1, &1));
+
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n1, n2, 2)).unwrap();
+    assert_eq!(simple_graph.edge_len(), 2);
+}
+
+#[test]
+fn test_adjacency_array_first_node_without_out_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n2, n0, 'c')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 'b')).unwrap();
+
+    let from_simple_graph = AdjacencyArray::from(&simple_graph);
+    let from_edges = AdjacencyArray::from_edges(
+        3,
+        vec![
+            Edge::new(n2, n0, 'c'),
+            Edge::new(n1, n0, 'a'),
+            Edge::new(n1, n2, 'b'),
+        ]
+        .into_iter(),
+        vec![(); 3],
+    )
+    .unwrap();
+
+    for adjacency_array in &[from_simple_graph, from_edges] {
+        assert_eq!(adjacency_array.check_invariants(), Ok(()));
+        let out_edges: Vec<Vec<_>> = adjacency_array
+            .node_id_iter()
+            .map(|id| adjacency_array.out_edges(id).map(usize::from).collect())
+            .collect();
+        assert_eq!(out_edges, vec![vec![], vec![0, 1], vec![2]]);
+        assert_eq!(adjacency_array.out_edge_data(n1), &['a', 'b']);
+        assert_eq!(adjacency_array.out_edge_ends(n2), &[n0]);
+    }
+}
+
+#[test]
+fn test_graphs_are_directed() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n0, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert!(simple_graph.is_directed());
+    assert!(adjacency_array.is_directed());
+}
+
+#[test]
+fn test_adjacency_array_stats() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    for window in nodes.windows(2) {
+        simple_graph
+            .add_edge(Edge::new(window[0], window[1], 'x'))
+            .unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let stats = adjacency_array.stats();
+    assert_eq!(stats.node_len, 4);
+    assert_eq!(stats.edge_len, 3);
+    assert_eq!(stats.first_out_len, 5);
+    assert!(stats.first_out_capacity >= stats.first_out_len);
+    assert!(stats.edge_ends_capacity >= stats.edge_len);
+    assert!(stats.node_data_capacity >= stats.node_len);
+    assert!(stats.edge_data_capacity >= stats.edge_len);
+
+    let empty = AdjacencyArray::<(), ()>::with_node_count(0).stats();
+    assert_eq!(
+        (empty.node_len, empty.edge_len, empty.first_out_len),
+        (0, 0, 1)
+    );
+}
+
+#[test]
+fn test_simple_graph_reverse_edge() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let e0 = simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    let e1 = simple_graph.add_edge(Edge::new(n1, n1, 2)).unwrap();
+
+    simple_graph.reverse_edge(e0);
+    assert_eq!(simple_graph.edge_start(e0), n1);
+    assert_eq!(simple_graph.edge_end(e0), n0);
+    assert_eq!(simple_graph.edge_data(e0), &1);
+    assert_eq!(simple_graph.edge(e1), EdgeRef::new(n1, n1, &2));
+
+    simple_graph.reverse_edge(e0);
+    assert_eq!(simple_graph.edge(e0), EdgeRef::new(n0, n1, &1));
+}
+
+#[test]
+#[should_panic]
+fn test_simple_graph_reverse_invalid_edge() {
+    let mut simple_graph = SimpleGraph::<(), ()>::new();
+    simple_graph.add_node(Node::new(()));
+    simple_graph.reverse_edge(EdgeId::from(0usize));
+}
+
+#[test]
+fn test_edge_ref_eq_undirected() {
+    let n0 = NodeId::from(0);
+    let n1 = NodeId::from(1);
+    let n2 = NodeId::from(2);
+    let edge = EdgeRef::new(n0, n1, &5);
+    assert!(edge.eq_undirected(&EdgeRef::new(n0, n1, &5)));
+    assert!(edge.eq_undirected(&EdgeRef::new(n1, n0, &5)));
+    assert_ne!(edge, EdgeRef::new(n1, n0, &5));
+    assert!(!edge.eq_undirected(&EdgeRef::new(n1, n0, &6)));
+    assert!(!edge.eq_undirected(&EdgeRef::new(n0, n2, &5)));
+    assert!(!edge.eq_undirected(&EdgeRef::new(n1, n1, &5)));
+    assert!(EdgeRef::new(n2, n2, &5).eq_undirected(&EdgeRef::new(n2, n2, &5)));
+}
+
+#[test]
+fn test_edge_from_indices() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+
+    let edge = Edge::from_indices(1, 0, 5);
+    assert_eq!((edge.start(), edge.end(), *edge.data()), (n1, n0, 5));
+    let e0 = simple_graph.add_edge(edge).unwrap();
+    assert_eq!(simple_graph.edge(e0), EdgeRef::new(n1, n0, &5));
+
+    assert!(matches!(
+        simple_graph.add_edge(Edge::from_indices(0, 2, 6)),
+        Err(GraphModificationError::EndNodeDoesNotExist)
+    ));
+}
+
+#[test]
+fn test_adjacency_array_approx_eq() {
+    let weighted_graph = |weights: &[f64]| {
+        let mut simple_graph = SimpleGraph::new();
+        let nodes: Vec<_> = (0..3)
+            .map(|i| simple_graph.add_node(Node::new(i)))
+            .collect();
+        for (i, &weight) in weights.iter().enumerate() {
+            simple_graph
+                .add_edge(Edge::new(nodes[i % 3], nodes[(i + 1) % 3], weight))
+                .unwrap();
+        }
+        AdjacencyArray::from(&simple_graph)
+    };
+
+    let original = weighted_graph(&[0.1 + 0.2, 1.5, 2.0]);
+    let close = weighted_graph(&[0.3, 1.5 + 1e-10, 2.0]);
+    let far = weighted_graph(&[0.3, 1.6, 2.0]);
+    let different_topology = weighted_graph(&[0.3, 1.5, 2.0, 4.0]);
+
+    assert_ne!(*original.edge_data(EdgeId::from(0usize)), 0.3);
+    assert!(original.approx_eq(&close, 1e-9));
+    assert!(close.approx_eq(&original, 1e-9));
+    assert!(!original.approx_eq(&far, 1e-9));
+    assert!(original.approx_eq(&far, 0.2));
+    assert!(!original.approx_eq(&different_topology, 1.0));
+}
+
+#[test]
+fn test_adjacency_array_from_raw_parts_empty_first_out() {
+    let error =
+        AdjacencyArray::<(), ()>::from_raw_parts(Vec::new(), Vec::new(), Vec::new(), Vec::new())
+            .unwrap_err();
+    assert!(error.contains("first_out is empty"), "{}", error);
+
+    let empty = AdjacencyArray::<(), ()>::from_raw_parts(
+        vec![EdgeId::from(0usize)],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .unwrap();
+    assert_eq!(empty.node_len(), 0);
+    assert_eq!(empty.node_id_iter().count(), 0);
+}
+
+#[test]
+fn test_simple_graph_retain_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..3)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    for &(start, end, weight) in &[(0, 1, 5), (1, 2, 1), (2, 0, 7), (0, 2, 2), (1, 1, 9)] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], weight))
+            .unwrap();
+    }
+
+    let mut visited_ids = Vec::new();
+    simple_graph.retain_edges(|id, edge| {
+        visited_ids.push(id);
+        *edge.data() >= 5
+    });
+    assert_eq!(visited_ids, (0..5).map(EdgeId::from).collect::<Vec<_>>());
+    assert_eq!(simple_graph.edge_len(), 3);
+    assert_eq!(simple_graph.node_len(), 3);
+    let edges: Vec<_> = simple_graph
+        .edge_id_iter()
+        .map(|id| simple_graph.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(nodes[0], nodes[1], &5),
+            EdgeRef::new(nodes[2], nodes[0], &7),
+            EdgeRef::new(nodes[1], nodes[1], &9),
+        ]
+    );
+}
+
+#[test]
+fn test_simple_graph_retain_nodes() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..5)
+        .map(|i| simple_graph.add_node(Node::new(i)))
+        .collect();
+    for &(start, end, data) in &[
+        (0, 1, 'a'),
+        (2, 4, 'b'),
+        (3, 2, 'c'),
+        (4, 0, 'd'),
+        (2, 2, 'e'),
+    ] {
+        simple_graph
+            .add_edge(Edge::new(nodes[start], nodes[end], data))
+            .unwrap();
+    }
+
+    let node_map = simple_graph.retain_nodes(|id, node| id != nodes[3] && *node.data() != 1);
+    let new_nodes: Vec<_> = (0..3).map(NodeId::from).collect();
+    assert_eq!(
+        node_map,
+        vec![
+            Some(new_nodes[0]),
+            None,
+            Some(new_nodes[1]),
+            None,
+            Some(new_nodes[2])
+        ]
+    );
+    let node_data: Vec<_> = simple_graph
+        .node_id_iter()
+        .map(|id| *simple_graph.node_data(id))
+        .collect();
+    assert_eq!(node_data, vec![0, 2, 4]);
+    let edges: Vec<_> = simple_graph
+        .edge_id_iter()
+        .map(|id| simple_graph.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(new_nodes[1], new_nodes[2], &'b'),
+            EdgeRef::new(new_nodes[2], new_nodes[0], &'d'),
+            EdgeRef::new(new_nodes[1], new_nodes[1], &'e'),
+        ]
+    );
+}
+
+#[test]
+fn test_simple_graph_from_adjacency_lists() {
+    let graph = SimpleGraph::from_adjacency_lists(vec![vec![1, 2], vec![], vec![0, 2]]).unwrap();
+    assert_eq!(graph.node_len(), 3);
+    let edges: Vec<_> = graph
+        .edge_id_iter()
+        .map(|id| graph.edge_endpoints(id))
+        .collect();
+    let n0 = NodeId::from(0);
+    let n1 = NodeId::from(1);
+    let n2 = NodeId::from(2);
+    assert_eq!(edges, vec![(n0, n1), (n0, n2), (n2, n0), (n2, n2)]);
+
+    let empty = SimpleGraph::from_adjacency_lists(Vec::new()).unwrap();
+    assert_eq!(empty.node_len(), 0);
+    assert_eq!(empty.edge_len(), 0);
+}
+
+#[test]
+fn test_simple_graph_from_adjacency_lists_out_of_range() {
+    assert!(matches!(
+        SimpleGraph::from_adjacency_lists(vec![vec![1], vec![2]]),
+        Err(GraphModificationError::EndNodeDoesNotExist)
+    ));
+}
+
+#[test]
+fn test_simple_graph_dedup_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n1, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 'a')).unwrap();
+    simple_graph.dedup_edges();
+
+    assert_eq!(simple_graph.node_len(), 2);
+    let edges: Vec<_> = simple_graph
+        .edge_id_iter()
+        .map(|id| simple_graph.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![EdgeRef::new(n0, n1, &'a'), EdgeRef::new(n1, n0, &'a')]
+    );
+}
+
+#[test]
+fn test_simple_graph_dedup_edges_keeps_different_data() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    for &data in &[1, 2, 1, 2, 3, 1] {
+        simple_graph.add_edge(Edge::new(n0, n1, data)).unwrap();
+    }
+    simple_graph.add_edge(Edge::new(n1, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 1)).unwrap();
+    simple_graph.dedup_edges();
+
+    let edges: Vec<_> = simple_graph
+        .edge_id_iter()
+        .map(|id| simple_graph.edge(id))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            EdgeRef::new(n0, n1, &1),
+            EdgeRef::new(n0, n1, &2),
+            EdgeRef::new(n0, n1, &3),
+            EdgeRef::new(n1, n1, &1),
+        ]
+    );
+}
+
+#[test]
+fn test_simple_graph_dedup_edges_high_degree() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    for data in (0..10_000).rev().chain(0..10_000) {
+        simple_graph
+            .add_edge(Edge::new(n0, n1, data % 5_000))
+            .unwrap();
+    }
+    simple_graph.dedup_edges();
+
+    let data: Vec<_> = simple_graph
+        .edge_id_iter()
+        .map(|id| *simple_graph.edge_data(id))
+        .collect();
+    assert_eq!(
+        data,
+        (5_000..10_000)
+            .rev()
+            .map(|data| data % 5_000)
+            .collect::<Vec<_>>()
+    );
+}
@@ -1,6 +1,10 @@
 use graphrepresentations::{
-    adjacencyarray::AdjacencyArray,
-    graph::{Edge, EdgeRef, Graph, MutableGraph, Node},
+    adjacencyarray::{AdjacencyArray, ConversionScratch},
+    generators::gnp_random_graph,
+    graph::{
+        assert_edge_ids_preserved, convert, same_structure, Edge, EdgeRef, ForwardNavigableGraph,
+        Graph, MutableGraph, Node,
+    },
     simplegraph::SimpleGraph,
 };
 
@@ -49,4 +53,242 @@ fn test_adjacency_array_construction_simple_example() {
         assert_eq!(simple_graph.edge(sg), adjacency_array.edge(aa));
     }
     assert_eq!(simple_graph.edge_len(), adjacency_array.edge_len());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_generic_conversion_round_trip() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n2, 4)).unwrap();
+
+    let adjacency_array: AdjacencyArray<_, _> = AdjacencyArray::from(&simple_graph);
+    let round_trip: SimpleGraph<_, _> = convert(&adjacency_array);
+
+    assert_eq!(simple_graph.node_len(), round_trip.node_len());
+    for id in simple_graph.node_id_iter() {
+        assert_eq!(simple_graph.node_data(id), round_trip.node_data(id));
+    }
+    assert_eq!(simple_graph.edge_len(), round_trip.edge_len());
+    for id in simple_graph.edge_id_iter() {
+        assert_eq!(simple_graph.edge(id), round_trip.edge(id));
+    }
+}
+
+#[test]
+fn test_simple_graph_from_adjacency_array() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n2, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 3)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let mut edited = SimpleGraph::from(&adjacency_array);
+    for id in adjacency_array.node_id_iter() {
+        assert_eq!(adjacency_array.node_data(id), edited.node_data(id));
+    }
+    let aa_edge_iter = adjacency_array.edge_id_iter();
+    let sg_edge_iter = edited.edge_id_iter();
+    assert_eq!(adjacency_array.edge_len(), edited.edge_len());
+    for (aa, sg) in aa_edge_iter.zip(sg_edge_iter) {
+        assert_eq!(adjacency_array.edge(aa), edited.edge(sg));
+    }
+
+    let n3 = edited.add_node(Node::new('d'));
+    edited.add_edge(Edge::new(n2, n3, 4)).unwrap();
+    let reconverted = AdjacencyArray::from(&edited);
+    assert_eq!(reconverted.node_len(), 4);
+    assert_eq!(reconverted.node_data(n3), &'d');
+    assert_eq!(reconverted.edge_len(), 4);
+    let last_edge = reconverted.edge_id_iter().next_back().unwrap();
+    assert_eq!(reconverted.edge(last_edge), EdgeRef::new(n2, n3, &4));
+}
+
+#[test]
+fn test_adjacency_array_check_invariants() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(0));
+    let n1 = simple_graph.add_node(Node::new(1));
+    simple_graph.add_node(Node::new(2));
+    simple_graph.add_edge(Edge::new(n1, n0, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(
+        AdjacencyArray::from(&SimpleGraph::<(), ()>::new()).check_invariants(),
+        Ok(())
+    );
+}
+
+fn unsorted_edges_example() -> SimpleGraph<(), char> {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n2, n0, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 'b')).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n1, 'c')).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 'd')).unwrap();
+    simple_graph
+}
+
+#[test]
+fn test_edge_ids_only_preserved_for_sorted_edges() {
+    let mut simple_graph = unsorted_edges_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    // Edges are grouped by start node, so unsorted edges receive new ids.
+    let edge_data: Vec<_> = adjacency_array
+        .edge_id_iter()
+        .map(|id| *adjacency_array.edge_data(id))
+        .collect();
+    assert_eq!(edge_data, vec!['b', 'd', 'a', 'c']);
+
+    simple_graph.sort_edges_by_start();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    assert_edge_ids_preserved(&simple_graph, &adjacency_array);
+}
+
+#[test]
+#[should_panic(expected = "differs")]
+fn test_assert_edge_ids_preserved_detects_reordering() {
+    let simple_graph = unsorted_edges_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    assert_edge_ids_preserved(&simple_graph, &adjacency_array);
+}
+
+#[test]
+fn test_adjacency_array_from_simple_stable() {
+    let mut simple_graph = unsorted_edges_example();
+    simple_graph.sort_edges_by_start();
+    let adjacency_array = AdjacencyArray::from_simple_stable(&simple_graph).unwrap();
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(adjacency_array.node_len(), simple_graph.node_len());
+    assert_eq!(adjacency_array.edge_len(), simple_graph.edge_len());
+    for id in simple_graph.edge_id_iter() {
+        assert_eq!(adjacency_array.edge(id), simple_graph.edge(id));
+    }
+
+    let mut isolated_nodes = SimpleGraph::new();
+    for _ in 0..3 {
+        isolated_nodes.add_node(Node::new(()));
+    }
+    let adjacency_array = AdjacencyArray::<(), ()>::from_simple_stable(&isolated_nodes).unwrap();
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+    assert_eq!(adjacency_array.node_len(), 3);
+}
+
+#[test]
+fn test_adjacency_array_from_simple_stable_unsorted() {
+    assert!(AdjacencyArray::from_simple_stable(&unsorted_edges_example()).is_none());
+}
+
+fn assert_same_adjacency_array<N: PartialEq + std::fmt::Debug, E: PartialEq + std::fmt::Debug>(
+    actual: &AdjacencyArray<N, E>,
+    expected: &AdjacencyArray<N, E>,
+) {
+    assert_eq!(actual.check_invariants(), Ok(()));
+    assert_eq!(actual.node_len(), expected.node_len());
+    assert_edge_ids_preserved(expected, actual);
+    for id in expected.node_id_iter() {
+        assert_eq!(actual.node_data(id), expected.node_data(id));
+        assert_eq!(actual.out_edge_ends(id), expected.out_edge_ends(id));
+    }
+}
+
+#[test]
+fn test_adjacency_array_convert_into() {
+    let mut scratch = ConversionScratch::new();
+    let large = gnp_random_graph(500, 0.02, 3);
+    assert!(large.edge_len() > 1000);
+    let converted = AdjacencyArray::convert_into(&large, &mut scratch);
+    assert_same_adjacency_array(&converted, &AdjacencyArray::from(&large));
+    let memory_bytes = scratch.memory_bytes();
+    assert!(memory_bytes > 0);
+
+    // The second graph is smaller, so the buffers of the first conversion must be reused and reset properly.
+    let unsorted = unsorted_edges_example();
+    let converted = AdjacencyArray::convert_into(&unsorted, &mut scratch);
+    assert_same_adjacency_array(&converted, &AdjacencyArray::from(&unsorted));
+    assert_eq!(scratch.memory_bytes(), memory_bytes);
+
+    let converted = AdjacencyArray::convert_into(&large, &mut scratch);
+    assert_same_adjacency_array(&converted, &AdjacencyArray::from(&large));
+    assert_eq!(scratch.memory_bytes(), memory_bytes);
+
+    let empty = SimpleGraph::<(), char>::new();
+    let converted = AdjacencyArray::convert_into(&empty, &mut scratch);
+    assert_eq!(converted.node_len(), 0);
+    assert_eq!(converted.edge_len(), 0);
+}
+
+#[test]
+fn test_adjacency_array_forget_edge_data() {
+    let simple_graph = unsorted_edges_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    let endpoints: Vec<_> = adjacency_array
+        .edge_id_iter()
+        .map(|id| adjacency_array.edge_endpoints(id))
+        .collect();
+    let out_edges: Vec<Vec<_>> = adjacency_array
+        .node_id_iter()
+        .map(|id| adjacency_array.out_edges(id).collect())
+        .collect();
+
+    let topology = adjacency_array.forget_edge_data();
+    assert_eq!(topology.check_invariants(), Ok(()));
+    assert_eq!(topology.node_len(), 3);
+    let topology_endpoints: Vec<_> = topology
+        .edge_id_iter()
+        .map(|id| topology.edge_endpoints(id))
+        .collect();
+    assert_eq!(topology_endpoints, endpoints);
+    for id in topology.node_id_iter() {
+        assert!(topology
+            .out_edges(id)
+            .eq(out_edges[<usize>::from(id)].iter().copied()));
+    }
+    assert!(topology
+        .edge_id_iter()
+        .all(|id| topology.edge_data(id) == &()));
+}
+
+#[test]
+fn test_adjacency_array_to_csr() {
+    let simple_graph = unsorted_edges_example();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let (indptr, indices, data) = adjacency_array.to_csr();
+    assert_eq!(indptr, vec![0, 2, 2, 4]);
+    assert_eq!(indices, vec![1, 2, 0, 1]);
+    assert_eq!(data, vec![&'b', &'d', &'a', &'c']);
+}
+
+#[test]
+fn test_same_structure() {
+    let mut simple_graph = unsorted_edges_example();
+    assert!(same_structure(&simple_graph, &simple_graph));
+    assert!(!same_structure(
+        &simple_graph,
+        &AdjacencyArray::from(&simple_graph)
+    ));
+
+    simple_graph.sort_edges_by_start();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+    assert!(same_structure(&simple_graph, &adjacency_array));
+    assert!(same_structure(
+        &adjacency_array,
+        &convert::<_, _, _, SimpleGraph<_, _>>(&adjacency_array)
+    ));
+
+    simple_graph.add_node(Node::new(()));
+    assert!(!same_structure(&simple_graph, &adjacency_array));
+}
@@ -0,0 +1,43 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    graph::{Edge, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+};
+
+#[derive(Clone)]
+struct NoDebug;
+
+#[test]
+fn test_adjacency_array_debug() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(NoDebug));
+    let n1 = simple_graph.add_node(Node::new(NoDebug));
+    simple_graph.add_node(Node::new(NoDebug));
+    simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        format!("{:?}", adjacency_array),
+        "AdjacencyArray { node_len: 3, edge_len: 2, first_out_len: 4 }"
+    );
+}
+
+#[test]
+fn test_simple_graph_debug() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    simple_graph.add_edge(Edge::new(n0, n1, 7)).unwrap();
+    let debug = format!("{:?}", simple_graph);
+    assert!(debug.contains("'a'"), "{}", debug);
+    assert!(debug.contains("7"), "{}", debug);
+
+    for _ in 0..20 {
+        simple_graph.add_edge(Edge::new(n1, n0, 8)).unwrap();
+    }
+    assert_eq!(
+        format!("{:?}", simple_graph),
+        "SimpleGraph { node_len: 2, edge_len: 21 }"
+    );
+}
@@ -0,0 +1,56 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    graph::{DynGraph, Edge, EdgeRef, Graph, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+    EdgeId,
+};
+
+#[test]
+fn test_dyn_graph_heterogeneous_storage() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    simple_graph.add_edge(Edge::new(n1, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let graphs: Vec<Box<dyn DynGraph<char, i32>>> =
+        vec![Box::new(simple_graph), Box::new(adjacency_array)];
+    for graph in &graphs {
+        assert_eq!(graph.dyn_node_len(), 2);
+        assert_eq!(graph.dyn_edge_len(), 2);
+        let node_data: Vec<_> = graph
+            .dyn_node_ids()
+            .map(|id| *graph.dyn_node_data(id))
+            .collect();
+        assert_eq!(node_data, vec!['a', 'b']);
+        let mut edges: Vec<_> = graph.dyn_edge_ids().map(|id| graph.dyn_edge(id)).collect();
+        edges.sort_by_key(|edge| *edge.data());
+        assert_eq!(
+            edges,
+            vec![EdgeRef::new(n1, n0, &1), EdgeRef::new(n0, n1, &2)]
+        );
+        assert!(graph.dyn_is_node_id_valid(n1));
+        assert!(graph.dyn_is_edge_id_valid(EdgeId::from(1)));
+        assert!(!graph.dyn_is_edge_id_valid(EdgeId::from(2)));
+        assert_eq!(
+            graph.dyn_edge_data(EdgeId::from(0)),
+            graph.dyn_edge(EdgeId::from(0)).data()
+        );
+    }
+}
+
+#[test]
+fn test_dyn_graph_with_graph_in_scope() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n0, n0, ())).unwrap();
+
+    // Both traits are implemented by `SimpleGraph`, so their methods must not collide.
+    assert_eq!(simple_graph.node_len(), simple_graph.dyn_node_len());
+    assert_eq!(simple_graph.edge_len(), simple_graph.dyn_edge_len());
+    assert_eq!(
+        simple_graph.edge(EdgeId::from(0)),
+        simple_graph.dyn_edge(EdgeId::from(0))
+    );
+}
@@ -0,0 +1,47 @@
+use graphrepresentations::{algorithms::CycleError, graph::GraphModificationError};
+
+#[test]
+fn test_graph_modification_error_display() {
+    assert_eq!(
+        GraphModificationError::StartNodeDoesNotExist.to_string(),
+        "The start node of the edge does not exist"
+    );
+    assert_eq!(
+        GraphModificationError::EndNodeDoesNotExist.to_string(),
+        "The end node of the edge does not exist"
+    );
+    assert_eq!(
+        GraphModificationError::NodeDataLenMismatch.to_string(),
+        "The node data len does not match the node count"
+    );
+}
+
+// The `Error` implementations require the standard library.
+#[cfg(feature = "std")]
+mod std_error {
+    use graphrepresentations::{
+        graph::{Edge, MutableGraph, Node},
+        simplegraph::SimpleGraph,
+        NodeId,
+    };
+    use std::error::Error;
+
+    fn add_dangling_edge() -> Result<(), Box<dyn Error>> {
+        let mut simple_graph = SimpleGraph::new();
+        let n0 = simple_graph.add_node(Node::new(()));
+        simple_graph.add_edge(Edge::new(n0, n0, ()))?;
+        simple_graph.add_edge(Edge::new(n0, NodeId::from(1), ()))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_modification_error_boxed() {
+        let error = add_dangling_edge().unwrap_err();
+        assert_eq!(error.to_string(), "The end node of the edge does not exist");
+    }
+}
+
+#[test]
+fn test_cycle_error_display() {
+    assert_eq!(CycleError.to_string(), "The graph contains a cycle");
+}
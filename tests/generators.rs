@@ -0,0 +1,98 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    algorithms::out_degree_sequence,
+    generators::{complete_graph, cycle_graph, gnp_random_graph, grid_graph, path_graph},
+    graph::{EdgeRef, Graph},
+    EdgeId, NodeId,
+};
+
+#[test]
+fn test_gnp_random_graph_reproducible() {
+    let first = gnp_random_graph(20, 0.3, 42);
+    let second = gnp_random_graph(20, 0.3, 42);
+    assert_eq!(first.node_len(), 20);
+    assert_eq!(first.edge_len(), second.edge_len());
+    for id in first.edge_id_iter() {
+        assert_eq!(first.edge(id), second.edge(id));
+    }
+    assert!(first.edge_len() > 0);
+    assert!(first.edge_len() < 20 * 19);
+}
+
+#[test]
+fn test_gnp_random_graph_complete() {
+    let graph = gnp_random_graph(6, 1.0, 7);
+    assert_eq!(graph.node_len(), 6);
+    assert_eq!(graph.edge_len(), 6 * 5);
+    for id in graph.edge_id_iter() {
+        assert_ne!(graph.edge_start(id), graph.edge_end(id));
+    }
+
+    let graph = gnp_random_graph(6, 0.0, 7);
+    assert_eq!(graph.edge_len(), 0);
+}
+
+#[test]
+fn test_path_graph() {
+    let graph = path_graph(5);
+    assert_eq!(graph.node_len(), 5);
+    assert_eq!(graph.edge_len(), 4);
+    assert_eq!(
+        graph.edge(EdgeId::from(0)),
+        EdgeRef::new(NodeId::from(0), NodeId::from(1), &())
+    );
+    assert_eq!(
+        graph.edge(EdgeId::from(3)),
+        EdgeRef::new(NodeId::from(3), NodeId::from(4), &())
+    );
+    assert_eq!(path_graph(0).edge_len(), 0);
+    assert_eq!(path_graph(1).edge_len(), 0);
+}
+
+#[test]
+fn test_cycle_graph() {
+    let graph = cycle_graph(5);
+    assert_eq!(graph.node_len(), 5);
+    assert_eq!(graph.edge_len(), 5);
+    assert_eq!(
+        graph.edge(EdgeId::from(4)),
+        EdgeRef::new(NodeId::from(4), NodeId::from(0), &())
+    );
+    assert_eq!(cycle_graph(0).edge_len(), 0);
+}
+
+#[test]
+fn test_complete_graph() {
+    let graph = complete_graph(5);
+    assert_eq!(graph.node_len(), 5);
+    assert_eq!(graph.edge_len(), 5 * 4);
+    assert_eq!(
+        graph.edge(EdgeId::from(0)),
+        EdgeRef::new(NodeId::from(0), NodeId::from(1), &())
+    );
+    assert_eq!(
+        graph.edge(EdgeId::from(4)),
+        EdgeRef::new(NodeId::from(1), NodeId::from(0), &())
+    );
+}
+
+#[test]
+fn test_grid_graph() {
+    let (width, height) = (4, 3);
+    let graph = grid_graph(width, height);
+    assert_eq!(graph.node_len(), 12);
+    assert_eq!(
+        graph.edge_len(),
+        2 * ((width - 1) * height + width * (height - 1)) as u32
+    );
+
+    let degrees = out_degree_sequence(&AdjacencyArray::from(&graph));
+    let degree = |row: usize, col: usize| degrees[row * width + col];
+    for &(row, col) in &[(0, 0), (0, 3), (2, 0), (2, 3)] {
+        assert_eq!(degree(row, col), 2);
+    }
+    assert_eq!(degree(1, 1), 4);
+    assert_eq!(degree(1, 2), 4);
+    assert_eq!(degree(0, 1), 3);
+    assert_eq!(degree(1, 0), 3);
+}
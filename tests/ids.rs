@@ -0,0 +1,120 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    graph::{Edge, EdgeRef, Graph, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+    EdgeId, NodeId,
+};
+use std::collections::HashSet;
+
+#[test]
+fn test_ids_in_hash_set() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(0));
+    let n1 = simple_graph.add_node(Node::new(1));
+    let n2 = simple_graph.add_node(Node::new(2));
+    let e0 = simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    let e1 = simple_graph.add_edge(Edge::new(n1, n2, ())).unwrap();
+
+    let mut nodes = HashSet::new();
+    assert!(nodes.insert(n0));
+    assert!(nodes.insert(n2));
+    assert!(!nodes.insert(n0));
+    assert!(nodes.contains(&n0));
+    assert!(!nodes.contains(&n1));
+    assert!(nodes.contains(&n2));
+
+    let edges: HashSet<_> = vec![e0].into_iter().collect();
+    assert!(edges.contains(&e0));
+    assert!(!edges.contains(&e1));
+}
+
+#[test]
+fn test_get_data_with_valid_and_invalid_ids() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let e0 = simple_graph.add_edge(Edge::new(n0, n1, 5)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(simple_graph.get_node_data(n1), Some(&'b'));
+    assert_eq!(simple_graph.get_node_data(NodeId::from(2)), None);
+    assert_eq!(simple_graph.get_edge_data(e0), Some(&5));
+    assert_eq!(simple_graph.get_edge_data(EdgeId::from(1)), None);
+
+    assert_eq!(adjacency_array.get_node_data(n1), Some(&'b'));
+    assert_eq!(adjacency_array.get_node_data(NodeId::from(2)), None);
+    assert_eq!(adjacency_array.get_edge_data(e0), Some(&5));
+    assert_eq!(adjacency_array.get_edge_data(EdgeId::from(1)), None);
+}
+
+fn reversed_ids<N, E, G: Graph<N, E>>(graph: &G) -> (Vec<NodeId>, Vec<EdgeId>) {
+    assert_eq!(graph.node_id_iter().len(), graph.node_len() as usize);
+    assert_eq!(graph.edge_id_iter().len(), graph.edge_len() as usize);
+    (
+        graph.node_id_iter().rev().collect(),
+        graph.edge_id_iter().rev().collect(),
+    )
+}
+
+#[test]
+fn test_id_iterators_reversed() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(0));
+    let n1 = simple_graph.add_node(Node::new(1));
+    let n2 = simple_graph.add_node(Node::new(2));
+    let e0 = simple_graph.add_edge(Edge::new(n0, n1, ())).unwrap();
+    let e1 = simple_graph.add_edge(Edge::new(n1, n2, ())).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(
+        reversed_ids(&simple_graph),
+        (vec![n2, n1, n0], vec![e1, e0])
+    );
+    assert_eq!(
+        reversed_ids(&adjacency_array),
+        (vec![n2, n1, n0], vec![e1, e0])
+    );
+}
+
+#[test]
+fn test_node_id_from_larger_graph() {
+    let mut large_graph = SimpleGraph::<_, ()>::new();
+    let large_nodes: Vec<_> = (0..5).map(|i| large_graph.add_node(Node::new(i))).collect();
+    let mut small_graph = SimpleGraph::<_, ()>::new();
+    small_graph.add_node(Node::new(0));
+    small_graph.add_node(Node::new(1));
+    let small_adjacency_array = AdjacencyArray::from(&small_graph);
+
+    let foreign_id = large_nodes[3];
+    assert!(foreign_id.is_valid());
+    assert!(large_graph.node_id_in_bounds(foreign_id));
+    assert!(large_graph.is_node_id_valid(foreign_id));
+    assert!(!small_graph.node_id_in_bounds(foreign_id));
+    assert!(!small_graph.is_node_id_valid(foreign_id));
+    assert!(!small_adjacency_array.node_id_in_bounds(foreign_id));
+    assert!(!small_adjacency_array.is_node_id_valid(foreign_id));
+    assert_eq!(small_graph.get_node_data(foreign_id), None);
+
+    let shared_id = large_nodes[1];
+    assert!(small_graph.node_id_in_bounds(shared_id));
+    assert!(small_adjacency_array.node_id_in_bounds(shared_id));
+}
+
+#[test]
+fn test_get_edge_with_valid_and_invalid_ids() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let e0 = simple_graph.add_edge(Edge::new(n1, n0, 5)).unwrap();
+    let e1 = simple_graph.add_edge(Edge::new(n0, n1, 6)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(simple_graph.get_edge(e0), Some(EdgeRef::new(n1, n0, &5)));
+    assert_eq!(simple_graph.get_edge(e1), Some(EdgeRef::new(n0, n1, &6)));
+    assert_eq!(simple_graph.get_edge(EdgeId::from(2)), None);
+
+    for id in adjacency_array.edge_id_iter() {
+        assert_eq!(adjacency_array.get_edge(id), Some(adjacency_array.edge(id)));
+    }
+    assert_eq!(adjacency_array.get_edge(EdgeId::from(2)), None);
+}
@@ -0,0 +1,56 @@
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    algorithms::out_degree_sequence,
+    graph::{Edge, ForwardNavigableGraph, Graph, MutableGraph, Node},
+    labeledgraph::LabeledGraph,
+    simplegraph::SimpleGraph,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Meta {
+    name: &'static str,
+    timestamp: u64,
+}
+
+#[test]
+fn test_labeled_adjacency_array() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    let mut labeled = LabeledGraph::new(
+        AdjacencyArray::from(&simple_graph),
+        Meta {
+            name: "example",
+            timestamp: 10,
+        },
+    );
+
+    assert_eq!(labeled.node_len(), 2);
+    assert_eq!(labeled.edge_len(), 1);
+    assert_eq!(labeled.node_data(n1), &'b');
+    assert_eq!(labeled.out_edges(n0).count(), 1);
+    assert_eq!(out_degree_sequence(labeled.graph()), vec![1, 0]);
+
+    labeled.meta_mut().timestamp += 5;
+    assert_eq!(
+        labeled.meta(),
+        &Meta {
+            name: "example",
+            timestamp: 15
+        }
+    );
+
+    let (graph, meta) = labeled.into_parts();
+    assert_eq!(graph.edge_len(), 1);
+    assert_eq!(meta.timestamp, 15);
+}
+
+#[test]
+fn test_labeled_simple_graph_is_mutable() {
+    let mut labeled = LabeledGraph::new(SimpleGraph::<(), ()>::new(), "mutable");
+    let n0 = labeled.add_node(Node::new(()));
+    labeled.add_edge(Edge::new(n0, n0, ())).unwrap();
+    assert_eq!(labeled.edge_len(), 1);
+    assert_eq!(*labeled.meta(), "mutable");
+}
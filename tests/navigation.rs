@@ -1,6 +1,7 @@
 use graphrepresentations::simplegraph::SimpleGraph;
-use graphrepresentations::graph::{MutableGraph, Node, Edge, ForwardNavigableGraph, Graph, EdgeRef};
+use graphrepresentations::graph::{MutableGraph, Node, Edge, ForwardNavigableGraph, BackwardNavigableGraph, Graph, EdgeRef};
 use graphrepresentations::adjacencyarray::AdjacencyArray;
+use graphrepresentations::NodeId;
 
 #[test]
 fn test_adjacency_array_navigation_simple_example() {
@@ -20,4 +21,265 @@ fn test_adjacency_array_navigation_simple_example() {
 
     let n1_out_edges: Vec<_> = adjacency_array.out_edges(n1).map(|id| adjacency_array.edge(id)).collect();
     assert_eq!(n1_out_edges, vec![EdgeRef::new(n1, n0, &2), EdgeRef::new(n1, n4, &3), EdgeRef::new(n1, n2, &4)]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_adjacency_array_out_edge_insertion_order() {
+    let mut simple_graph = SimpleGraph::new();
+    let nodes: Vec<_> = (0..4).map(|i| simple_graph.add_node(Node::new(i))).collect();
+    let edges = [(2, 0), (0, 3), (3, 3), (0, 1), (2, 2), (0, 0), (3, 1), (2, 1), (0, 2), (3, 0)];
+    for (index, &(start, end)) in edges.iter().enumerate() {
+        simple_graph.add_edge(Edge::new(nodes[start], nodes[end], index)).unwrap();
+    }
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    for (start, &node) in nodes.iter().enumerate() {
+        let expected: Vec<_> = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, &(edge_start, _))| edge_start == start)
+            .map(|(index, &(_, end))| (nodes[end], index))
+            .collect();
+        let actual: Vec<_> = adjacency_array
+            .out_edges(node)
+            .map(|id| (adjacency_array.edge_end(id), *adjacency_array.edge_data(id)))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_adjacency_array_backward_navigation() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n1, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n1, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 4)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 5)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph).with_backward_index();
+    assert_eq!(adjacency_array.check_invariants(), Ok(()));
+
+    let n1_in_edges: Vec<_> = adjacency_array.in_edges(n1).map(|id| adjacency_array.edge(id)).collect();
+    assert_eq!(n1_in_edges, vec![EdgeRef::new(n0, n1, &2), EdgeRef::new(n1, n1, &4), EdgeRef::new(n2, n1, &3)]);
+    let n0_in_edges: Vec<_> = adjacency_array.in_edges(n0).map(|id| adjacency_array.edge(id)).collect();
+    assert_eq!(n0_in_edges, vec![EdgeRef::new(n1, n0, &1)]);
+    assert_eq!(in_edge_data(&adjacency_array, n1), vec![2, 4, 3]);
+}
+
+fn in_edge_data<'a, G: BackwardNavigableGraph<'a, char, i32>>(graph: G, id: NodeId) -> Vec<i32> {
+    graph.in_edges(id).map(|id| *graph.edge_data(id)).collect()
+}
+
+#[test]
+fn test_adjacency_array_incident_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n1, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n1, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 4)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 5)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph).with_backward_index();
+
+    let n1_incident_edges: Vec<_> = adjacency_array.incident_edges(n1).map(|id| *adjacency_array.edge_data(id)).collect();
+    assert_eq!(n1_incident_edges, vec![1, 5, 2, 4, 3]);
+    let n0_incident_edges: Vec<_> = adjacency_array.incident_edges(n0).map(|id| *adjacency_array.edge_data(id)).collect();
+    assert_eq!(n0_incident_edges, vec![2, 1]);
+}
+
+#[test]
+fn test_edge_endpoints() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n2, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 4)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    for id in simple_graph.edge_id_iter() {
+        assert_eq!(simple_graph.edge_endpoints(id), (simple_graph.edge_start(id), simple_graph.edge_end(id)));
+    }
+    for id in adjacency_array.edge_id_iter() {
+        assert_eq!(adjacency_array.edge_endpoints(id), (adjacency_array.edge_start(id), adjacency_array.edge_end(id)));
+    }
+}
+
+#[test]
+fn test_adjacency_array_out_edge_slices() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n1, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 4)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    for id in adjacency_array.node_id_iter() {
+        let ends: Vec<_> = adjacency_array.out_edges(id).map(|edge_id| adjacency_array.edge_end(edge_id)).collect();
+        let data: Vec<_> = adjacency_array.out_edges(id).map(|edge_id| *adjacency_array.edge_data(edge_id)).collect();
+        assert_eq!(adjacency_array.out_edge_ends(id), ends.as_slice());
+        assert_eq!(adjacency_array.out_edge_data(id), data.as_slice());
+    }
+    assert_eq!(adjacency_array.out_edge_ends(n1), &[n0, n2, n1]);
+    assert_eq!(adjacency_array.out_edge_data(n1), &[1, 3, 4]);
+    assert!(adjacency_array.out_edge_ends(n2).is_empty());
+}
+
+#[test]
+fn test_adjacency_array_out_edge_refs() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    let n3 = simple_graph.add_node(Node::new('d'));
+    let n4 = simple_graph.add_node(Node::new('e'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n3, 5)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n4, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n2, 4)).unwrap();
+    simple_graph.add_edge(Edge::new(n3, n3, 6)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    for node in adjacency_array.node_id_iter() {
+        let out_edges: Vec<_> = adjacency_array.out_edges(node).map(|id| adjacency_array.edge(id)).collect();
+        let out_edge_refs: Vec<_> = adjacency_array.out_edge_refs(node).collect();
+        assert_eq!(out_edge_refs, out_edges);
+    }
+    let n1_out_edge_refs: Vec<_> = adjacency_array.out_edge_refs(n1).collect();
+    assert_eq!(n1_out_edge_refs, vec![EdgeRef::new(n1, n0, &2), EdgeRef::new(n1, n4, &3), EdgeRef::new(n1, n2, &4)]);
+}
+
+#[test]
+fn test_full_edge_iter() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    let n3 = simple_graph.add_node(Node::new('d'));
+    simple_graph.add_edge(Edge::new(n2, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n2, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n3, 4)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let simple_graph_edges: Vec<_> = simple_graph.full_edge_iter().collect();
+    let expected: Vec<_> = simple_graph.edge_id_iter().map(|id| (id, simple_graph.edge_start(id), simple_graph.edge_end(id), simple_graph.edge_data(id))).collect();
+    assert_eq!(simple_graph_edges, expected);
+
+    let adjacency_array_edges: Vec<_> = adjacency_array.full_edge_iter().collect();
+    let expected: Vec<_> = adjacency_array.edge_id_iter().map(|id| (id, adjacency_array.edge_start(id), adjacency_array.edge_end(id), adjacency_array.edge_data(id))).collect();
+    assert_eq!(adjacency_array_edges, expected);
+    let endpoints: Vec<_> = adjacency_array_edges.iter().map(|&(_, start, end, &data)| (start, end, data)).collect();
+    assert_eq!(endpoints, vec![(n0, n1, 2), (n0, n3, 4), (n2, n0, 1), (n2, n2, 3)]);
+}
+
+#[test]
+fn test_adjacency_array_edge_slices() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n1, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n1, 3)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let edge_ends = adjacency_array.edge_ends_slice();
+    let edge_data = adjacency_array.edge_data_slice();
+    assert_eq!(edge_ends.len(), adjacency_array.edge_len() as usize);
+    assert_eq!(edge_data.len(), adjacency_array.edge_len() as usize);
+    for id in adjacency_array.edge_id_iter() {
+        assert_eq!(edge_ends[<usize>::from(id)], adjacency_array.edge_end(id));
+        assert_eq!(&edge_data[<usize>::from(id)], adjacency_array.edge_data(id));
+    }
+    assert_eq!(edge_ends, &[n2, n0, n1]);
+    assert_eq!(edge_data, &[2, 1, 3]);
+}
+
+#[test]
+fn test_adjacency_array_edges_by_source() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    let n3 = simple_graph.add_node(Node::new('d'));
+    simple_graph.add_edge(Edge::new(n2, n0, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n2, n2, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n3, 4)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let groups: Vec<_> = adjacency_array.edges_by_source().collect();
+    assert_eq!(groups.len(), 4);
+    assert_eq!(groups[1], (n1, &[][..], &[][..]));
+
+    let reconstructed: Vec<_> = groups.iter().flat_map(|&(start, ends, data)| ends.iter().zip(data).map(move |(&end, data)| EdgeRef::new(start, end, data))).collect();
+    let expected: Vec<_> = adjacency_array.edge_id_iter().map(|id| adjacency_array.edge(id)).collect();
+    assert_eq!(reconstructed, expected);
+}
+
+#[test]
+fn test_adjacency_array_edges_between() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new('a'));
+    let n1 = simple_graph.add_node(Node::new('b'));
+    let n2 = simple_graph.add_node(Node::new('c'));
+    simple_graph.add_edge(Edge::new(n0, n1, 1)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n2, 2)).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 3)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 4)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 5)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let parallel_edges: Vec<_> = adjacency_array.edges_between(n0, n1).map(|id| *adjacency_array.edge_data(id)).collect();
+    assert_eq!(parallel_edges, vec![1, 4, 5]);
+    let reverse_edges: Vec<_> = adjacency_array.edges_between(n1, n0).map(|id| *adjacency_array.edge_data(id)).collect();
+    assert_eq!(reverse_edges, vec![3]);
+    assert_eq!(adjacency_array.edges_between(n2, n0).count(), 0);
+    assert_eq!(adjacency_array.edges_between(n1, n2).count(), 0);
+}
+
+#[test]
+fn test_self_loop_edges() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    let n2 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n2, n0, 'a')).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 'b')).unwrap();
+    let self_loop = simple_graph.add_edge(Edge::new(n2, n2, 'c')).unwrap();
+    simple_graph.add_edge(Edge::new(n1, n0, 'd')).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    assert_eq!(simple_graph.self_loop_edges().collect::<Vec<_>>(), vec![self_loop]);
+    let adjacency_array_self_loops: Vec<_> = adjacency_array.self_loop_edges().collect();
+    assert_eq!(adjacency_array_self_loops.len(), 1);
+    assert_eq!(adjacency_array.edge(adjacency_array_self_loops[0]), EdgeRef::new(n2, n2, &'c'));
+    assert_eq!(SimpleGraph::<(), ()>::new().self_loop_edges().next(), None);
+}
+
+#[test]
+fn test_adjacency_array_edge_weight() {
+    let mut simple_graph = SimpleGraph::new();
+    let n0 = simple_graph.add_node(Node::new(()));
+    let n1 = simple_graph.add_node(Node::new(()));
+    simple_graph.add_edge(Edge::new(n1, n0, 7u32)).unwrap();
+    simple_graph.add_edge(Edge::new(n0, n1, 3u32)).unwrap();
+    let adjacency_array = AdjacencyArray::from(&simple_graph);
+
+    let weights: Vec<u32> = adjacency_array.edge_id_iter().map(|id| adjacency_array.edge_weight(id)).collect();
+    assert_eq!(weights, vec![3, 7]);
+    let total: u32 = adjacency_array.out_edges(n0).map(|id| adjacency_array.edge_weight(id)).sum();
+    assert_eq!(total, 3);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rayon")]
+
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    graph::{Edge, ForwardNavigableGraph, Graph, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+    NodeId,
+};
+
+fn large_unsorted_graph(node_len: usize, edge_len: usize) -> SimpleGraph<usize, usize> {
+    let mut simple_graph = SimpleGraph::with_capacity(node_len, edge_len);
+    for i in 0..node_len {
+        simple_graph.add_node(Node::new(i));
+    }
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next_node = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        NodeId::from((state % node_len as u64) as usize)
+    };
+    for i in 0..edge_len {
+        let start = next_node();
+        let end = next_node();
+        simple_graph.add_edge(Edge::new(start, end, i)).unwrap();
+    }
+    simple_graph
+}
+
+#[test]
+fn test_par_from_matches_serial_conversion() {
+    let simple_graph = large_unsorted_graph(5_000, 100_000);
+    let serial = AdjacencyArray::from(&simple_graph);
+    let parallel = AdjacencyArray::par_from(&simple_graph);
+
+    assert_eq!(parallel.check_invariants(), Ok(()));
+    assert_eq!(parallel.node_len(), serial.node_len());
+    assert_eq!(parallel.edge_len(), serial.edge_len());
+    for id in serial.node_id_iter() {
+        assert_eq!(parallel.node_data(id), serial.node_data(id));
+        assert!(parallel.out_edges(id).eq(serial.out_edges(id)));
+    }
+    for id in serial.edge_id_iter() {
+        assert_eq!(parallel.edge(id), serial.edge(id));
+    }
+}
+
+#[test]
+fn test_par_from_small_graphs() {
+    for &(node_len, edge_len) in &[(1, 0), (1, 1), (3, 2), (10, 7)] {
+        let simple_graph = large_unsorted_graph(node_len, edge_len);
+        let serial = AdjacencyArray::from(&simple_graph);
+        let parallel = AdjacencyArray::par_from(&simple_graph);
+        assert_eq!(parallel.edge_len(), serial.edge_len());
+        for id in serial.edge_id_iter() {
+            assert_eq!(parallel.edge(id), serial.edge(id));
+        }
+    }
+
+    let empty = SimpleGraph::<(), ()>::new();
+    assert_eq!(AdjacencyArray::par_from(&empty).node_len(), 0);
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+use graphrepresentations::{
+    adjacencyarray::AdjacencyArray,
+    generators::gnp_random_graph,
+    graph::{Edge, Graph, MutableGraph, Node},
+    simplegraph::SimpleGraph,
+};
+use std::io::{self, Read, Write};
+
+fn write_u32(writer: &mut Vec<u8>, value: &u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u32(reader: &mut &[u8]) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn write_f64(writer: &mut Vec<u8>, value: &f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn read_f64(reader: &mut &[u8]) -> io::Result<f64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+fn medium_graph() -> AdjacencyArray<u32, f64> {
+    let topology = gnp_random_graph(300, 0.02, 7);
+    let mut simple_graph = SimpleGraph::new();
+    for id in topology.node_id_iter() {
+        simple_graph.add_node(Node::new(<usize>::from(id) as u32 * 3));
+    }
+    for id in topology.edge_id_iter() {
+        let edge = topology.edge(id);
+        simple_graph
+            .add_edge(Edge::new(
+                edge.start(),
+                edge.end(),
+                <usize>::from(id) as f64 / 8.0,
+            ))
+            .unwrap();
+    }
+    AdjacencyArray::from(&simple_graph)
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let adjacency_array = medium_graph();
+    assert!(adjacency_array.edge_len() > 1000);
+
+    let mut bytes = Vec::new();
+    adjacency_array
+        .write_binary(&mut bytes, write_u32, write_f64)
+        .unwrap();
+    let read = AdjacencyArray::read_binary(&mut bytes.as_slice(), read_u32, read_f64).unwrap();
+
+    assert_eq!(read.check_invariants(), Ok(()));
+    assert_eq!(read.node_len(), adjacency_array.node_len());
+    assert_eq!(read.edge_len(), adjacency_array.edge_len());
+    for id in adjacency_array.node_id_iter() {
+        assert_eq!(read.node_data(id), adjacency_array.node_data(id));
+    }
+    for id in adjacency_array.edge_id_iter() {
+        assert_eq!(read.edge(id), adjacency_array.edge(id));
+    }
+}
+
+#[test]
+fn test_binary_rejects_invalid_input() {
+    let adjacency_array = medium_graph();
+    let mut bytes = Vec::new();
+    adjacency_array
+        .write_binary(&mut bytes, write_u32, write_f64)
+        .unwrap();
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    let error =
+        AdjacencyArray::read_binary(&mut wrong_magic.as_slice(), read_u32, read_f64).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    let truncated = &bytes[..bytes.len() - 1];
+    let error = AdjacencyArray::read_binary(&mut &truncated[..], read_u32, read_f64).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_binary_rejects_invalid_ids() {
+    let adjacency_array = medium_graph();
+    let mut bytes = Vec::new();
+    adjacency_array
+        .write_binary(&mut bytes, write_u32, write_f64)
+        .unwrap();
+    let header_len = 8 + 2 * 8;
+    let first_out_len = 4 * (adjacency_array.node_len() as usize + 1);
+
+    // The last entry of first_out and the first edge end.
+    for &offset in &[header_len + first_out_len - 4, header_len + first_out_len] {
+        let mut invalid_id = bytes.clone();
+        invalid_id[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let result = AdjacencyArray::read_binary(&mut invalid_id.as_slice(), read_u32, read_f64);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
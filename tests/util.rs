@@ -0,0 +1,107 @@
+use graphrepresentations::{
+    util::{NodeBitset, PrefixSum},
+    EdgeId, NodeId,
+};
+
+#[test]
+fn test_node_bitset_word_boundaries() {
+    let mut bitset = NodeBitset::new(130);
+    let n63 = NodeId::from(63);
+    let n64 = NodeId::from(64);
+    let n65 = NodeId::from(65);
+    let n129 = NodeId::from(129);
+
+    for id in (0..130).map(NodeId::from) {
+        assert!(!bitset.contains(id));
+    }
+
+    bitset.set(n63);
+    bitset.set(n65);
+    assert!(bitset.contains(n63));
+    assert!(!bitset.contains(n64));
+    assert!(bitset.contains(n65));
+
+    bitset.set(n64);
+    bitset.set(n129);
+    assert!(bitset.contains(n64));
+    assert!(bitset.contains(n129));
+
+    bitset.clear(n64);
+    assert!(bitset.contains(n63));
+    assert!(!bitset.contains(n64));
+    assert!(bitset.contains(n65));
+
+    bitset.clear(n63);
+    bitset.clear(n63);
+    assert!(!bitset.contains(n63));
+    assert!(bitset.contains(n65));
+    assert_eq!(
+        (0..130)
+            .map(NodeId::from)
+            .filter(|&id| bitset.contains(id))
+            .count(),
+        2
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_node_bitset_out_of_bounds() {
+    let bitset = NodeBitset::new(64);
+    bitset.contains(NodeId::from(64));
+}
+
+#[test]
+fn test_prefix_sum_cloned_matches_in_place() {
+    let values = vec![3u64, 0, 5, 1, 0, 2];
+    let (exclusive, total) = values.prefix_sum_cloned();
+    let mut inclusive = values.clone();
+    let in_place_total = inclusive.prefix_sum();
+
+    assert_eq!(exclusive, vec![0, 3, 3, 8, 9, 9]);
+    assert_eq!(inclusive, vec![3, 3, 8, 9, 9, 11]);
+    assert_eq!(total, in_place_total);
+    for ((exclusive, inclusive), value) in exclusive.iter().zip(&inclusive).zip(&values) {
+        assert_eq!(exclusive + value, *inclusive);
+    }
+
+    let (empty, empty_total) = Vec::<u64>::new().prefix_sum_cloned();
+    assert!(empty.is_empty());
+    assert_eq!(empty_total, 0);
+}
+
+#[test]
+fn test_edge_id_prefix_sum_cloned_matches_in_place() {
+    let values: Vec<_> = [2usize, 0, 1, 4]
+        .iter()
+        .map(|&id| EdgeId::from(id))
+        .collect();
+    let (exclusive, total) = values.prefix_sum_cloned();
+    let mut inclusive = values.clone();
+    let in_place_total = inclusive.prefix_sum();
+
+    let to_usize = |ids: &[EdgeId]| -> Vec<usize> { ids.iter().map(|&id| id.into()).collect() };
+    assert_eq!(to_usize(&exclusive), vec![0, 2, 2, 3]);
+    assert_eq!(to_usize(&inclusive), vec![2, 2, 3, 7]);
+    assert_eq!(total, in_place_total);
+    assert_eq!(total, EdgeId::from(7usize));
+}
+
+#[test]
+fn test_edge_id_prefix_sum_matches_generic_prefix_sum() {
+    let raw_values = vec![0u32, 0, 4, 1, 0, 3, 2];
+    let mut generic = raw_values.clone();
+    let generic_total = generic.prefix_sum();
+    let mut edge_ids: Vec<_> = raw_values
+        .iter()
+        .map(|&id| EdgeId::from(id as usize))
+        .collect();
+    let edge_id_total = edge_ids.prefix_sum();
+
+    let edge_ids: Vec<_> = edge_ids
+        .into_iter()
+        .map(|id| <usize>::from(id) as u32)
+        .collect();
+    assert_eq!(edge_ids, generic);
+    assert_eq!(<usize>::from(edge_id_total) as u32, generic_total);
+}